@@ -6,6 +6,11 @@ use syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Stmt {
+    EnumDef(EnumDef),
+    FnDef(FnDef),
+    ModDef(ModDef),
+    StructDef(StructDef),
+    UseDef(UseDef),
     VariableDef(VariableDef),
     Expr(Expr),
 }
@@ -14,6 +19,11 @@ impl Stmt {
     #[inline]
     pub fn cast(node: SyntaxNode) -> Option<Self> {
         let result = match node.kind() {
+            SyntaxKind::EnumDef => Self::EnumDef(EnumDef(node)),
+            SyntaxKind::FnDef => Self::FnDef(FnDef(node)),
+            SyntaxKind::ModDef => Self::ModDef(ModDef(node)),
+            SyntaxKind::StructDef => Self::StructDef(StructDef(node)),
+            SyntaxKind::UseDef => Self::UseDef(UseDef(node)),
             SyntaxKind::VariableDef => Self::VariableDef(VariableDef(node)),
             _ => Self::Expr(Expr::cast(node)?),
         };
@@ -42,110 +52,1051 @@ impl Root {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FnDef(SyntaxNode);
+
+impl FnDef {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+
+    #[inline]
+    pub fn param_list(&self) -> Option<ParamList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::ParamList)
+            .map(ParamList)
+    }
+
+    /// Returns the type after the ‘->’, if there is one.
+    #[inline]
+    pub fn ret_ty(&self) -> Option<TypeRef> {
+        self.0.children().find_map(TypeRef::cast)
+    }
+
+    #[inline]
+    pub fn body(&self) -> Option<BlockExpr> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::BlockExpr)
+            .map(BlockExpr)
+    }
+
+    #[inline]
+    pub fn docs(&self) -> Option<String> {
+        docs(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParamList(SyntaxNode);
+
+impl ParamList {
+    #[inline]
+    pub fn params(&self) -> impl Iterator<Item = Param> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::Param)
+            .map(Param)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Param(SyntaxNode);
+
+impl Param {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<TypeRef> {
+        self.0.children().find_map(TypeRef::cast)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct VariableDef(SyntaxNode);
 
-impl VariableDef {
+impl VariableDef {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::VariableDef {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<TypeRef> {
+        self.0.children().find_map(TypeRef::cast)
+    }
+
+    #[inline]
+    pub fn value(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    #[inline]
+    pub fn docs(&self) -> Option<String> {
+        docs(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct StructDef(SyntaxNode);
+
+impl StructDef {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+
+    #[inline]
+    pub fn field_list(&self) -> Option<FieldDefList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::FieldDefList)
+            .map(FieldDefList)
+    }
+
+    #[inline]
+    pub fn docs(&self) -> Option<String> {
+        docs(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FieldDefList(SyntaxNode);
+
+impl FieldDefList {
+    #[inline]
+    pub fn fields(&self) -> impl Iterator<Item = FieldDef> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::FieldDef)
+            .map(FieldDef)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FieldDef(SyntaxNode);
+
+impl FieldDef {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+
+    #[inline]
+    pub fn ty(&self) -> Option<TypeRef> {
+        self.0.children().find_map(TypeRef::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct EnumDef(SyntaxNode);
+
+impl EnumDef {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+
+    #[inline]
+    pub fn variant_list(&self) -> Option<VariantList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::VariantList)
+            .map(VariantList)
+    }
+
+    #[inline]
+    pub fn docs(&self) -> Option<String> {
+        docs(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VariantList(SyntaxNode);
+
+impl VariantList {
+    #[inline]
+    pub fn variants(&self) -> impl Iterator<Item = Variant> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::Variant)
+            .map(Variant)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Variant(SyntaxNode);
+
+impl Variant {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+
+    /// Returns the variant’s fields, which are written like a function’s parameters. A variant
+    /// without any fields doesn’t have a list at all.
+    #[inline]
+    pub fn param_list(&self) -> Option<ParamList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::ParamList)
+            .map(ParamList)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ModDef(SyntaxNode);
+
+impl ModDef {
+    #[inline]
+    pub fn mod_kw(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::ModKw)
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+
+    #[inline]
+    pub fn docs(&self) -> Option<String> {
+        docs(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct UseDef(SyntaxNode);
+
+impl UseDef {
+    #[inline]
+    pub fn path(&self) -> Option<Path> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Path)
+            .map(Path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Path(SyntaxNode);
+
+impl Path {
+    /// Returns the names separated by ‘::’, e.g. `a` and `b` in `a::b`.
+    #[inline]
+    pub fn segments(&self) -> impl Iterator<Item = SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .filter(|token| token.kind() == SyntaxKind::Ident)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TypeRef(SyntaxNode);
+
+impl TypeRef {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::TypeRef {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> TypeRefKind {
+        if self.path().is_some() {
+            TypeRefKind::Path
+        } else if self.token(SyntaxKind::LParen).is_none() {
+            TypeRefKind::Named
+        } else if self.token(SyntaxKind::Comma).is_none() && self.fields().count() == 1 {
+            TypeRefKind::Paren
+        } else {
+            TypeRefKind::Tuple
+        }
+    }
+
+    /// Returns the name of a named type, e.g. `Int`.
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        self.token(SyntaxKind::Ident)
+    }
+
+    /// Returns the path of a type in another module, e.g. `shapes::Shape`.
+    #[inline]
+    pub fn path(&self) -> Option<Path> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Path)
+            .map(Path)
+    }
+
+    /// Returns the types of a tuple type’s fields, or the type inside the parentheses of a
+    /// parenthesized type.
+    #[inline]
+    pub fn fields(&self) -> impl Iterator<Item = TypeRef> {
+        self.0.children().filter_map(TypeRef::cast)
+    }
+
+    fn token(&self, kind: SyntaxKind) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeRefKind {
+    /// a type referred to by its name, e.g. `Int`
+    Named,
+    /// a type in another module, e.g. `shapes::Shape`
+    Path,
+    /// e.g. `(Int, Bool)` or `(Int,)`
+    Tuple,
+    /// a type in parentheses, e.g. `(Int)`, which is the same as the type on its own
+    Paren,
+}
+
+/// Returns the text of the doc comments leading a definition, one line per comment, with each
+/// comment’s `##` and the space following it stripped. Returns `None` if there are no doc comments.
+fn docs(node: &SyntaxNode) -> Option<String> {
+    let lines: Vec<_> = node
+        .children_with_tokens()
+        .map_while(SyntaxElement::into_token)
+        .take_while(|token| token.kind().is_trivia())
+        .filter(|token| token.kind() == SyntaxKind::DocComment)
+        .map(|token| {
+            let text = &token.text()["##".len()..];
+            text.strip_prefix(' ').unwrap_or(text).to_string()
+        })
+        .collect();
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Returns the first token directly inside a node that isn’t trivia. Whitespace and comments
+/// usually end up between nodes, but those leading the input end up inside the node that starts
+/// it if that node is the root, e.g. the `a` of `parser::parse_expr(" a")`.
+pub(crate) fn first_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .find(|token| !token.kind().is_trivia())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    ArrayExpr(ArrayExpr),
+    AssignExpr(AssignExpr),
+    BinaryExpr(BinaryExpr),
+    BlockExpr(BlockExpr),
+    BreakExpr(BreakExpr),
+    CallExpr(CallExpr),
+    ClosureExpr(ClosureExpr),
+    ContinueExpr(ContinueExpr),
+    FieldExpr(FieldExpr),
+    IfExpr(IfExpr),
+    IndexExpr(IndexExpr),
+    Literal(Literal),
+    LoopExpr(LoopExpr),
+    MatchExpr(MatchExpr),
+    ParenExpr(ParenExpr),
+    PathExpr(PathExpr),
+    RecordExpr(RecordExpr),
+    TupleExpr(TupleExpr),
+    UnaryExpr(UnaryExpr),
+    VariableRef(VariableRef),
+    WhileExpr(WhileExpr),
+}
+
+impl Expr {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        let result = match node.kind() {
+            SyntaxKind::ArrayExpr => Self::ArrayExpr(ArrayExpr(node)),
+            SyntaxKind::AssignExpr => Self::AssignExpr(AssignExpr(node)),
+            SyntaxKind::BlockExpr => Self::BlockExpr(BlockExpr(node)),
+            SyntaxKind::BreakExpr => Self::BreakExpr(BreakExpr(node)),
+            SyntaxKind::CallExpr => Self::CallExpr(CallExpr(node)),
+            SyntaxKind::ClosureExpr => Self::ClosureExpr(ClosureExpr(node)),
+            SyntaxKind::ContinueExpr => Self::ContinueExpr(ContinueExpr(node)),
+            SyntaxKind::FieldExpr => Self::FieldExpr(FieldExpr(node)),
+            SyntaxKind::IfExpr => Self::IfExpr(IfExpr(node)),
+            SyntaxKind::IndexExpr => Self::IndexExpr(IndexExpr(node)),
+            SyntaxKind::InfixExpr => Self::BinaryExpr(BinaryExpr(node)),
+            SyntaxKind::Literal => Self::Literal(Literal(node)),
+            SyntaxKind::LoopExpr => Self::LoopExpr(LoopExpr(node)),
+            SyntaxKind::MatchExpr => Self::MatchExpr(MatchExpr(node)),
+            SyntaxKind::ParenExpr => Self::ParenExpr(ParenExpr(node)),
+            SyntaxKind::PathExpr => Self::PathExpr(PathExpr(node)),
+            SyntaxKind::PrefixExpr => Self::UnaryExpr(UnaryExpr(node)),
+            SyntaxKind::RecordExpr => Self::RecordExpr(RecordExpr(node)),
+            SyntaxKind::TupleExpr => Self::TupleExpr(TupleExpr(node)),
+            SyntaxKind::VariableRef => Self::VariableRef(VariableRef(node)),
+            SyntaxKind::WhileExpr => Self::WhileExpr(WhileExpr(node)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    #[inline]
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::ArrayExpr(ArrayExpr(node))
+            | Self::AssignExpr(AssignExpr(node))
+            | Self::BinaryExpr(BinaryExpr(node))
+            | Self::BlockExpr(BlockExpr(node))
+            | Self::BreakExpr(BreakExpr(node))
+            | Self::CallExpr(CallExpr(node))
+            | Self::ClosureExpr(ClosureExpr(node))
+            | Self::ContinueExpr(ContinueExpr(node))
+            | Self::FieldExpr(FieldExpr(node))
+            | Self::IfExpr(IfExpr(node))
+            | Self::IndexExpr(IndexExpr(node))
+            | Self::Literal(Literal(node))
+            | Self::LoopExpr(LoopExpr(node))
+            | Self::MatchExpr(MatchExpr(node))
+            | Self::ParenExpr(ParenExpr(node))
+            | Self::PathExpr(PathExpr(node))
+            | Self::RecordExpr(RecordExpr(node))
+            | Self::TupleExpr(TupleExpr(node))
+            | Self::UnaryExpr(UnaryExpr(node))
+            | Self::VariableRef(VariableRef(node))
+            | Self::WhileExpr(WhileExpr(node)) => node,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArrayExpr(SyntaxNode);
+
+impl ArrayExpr {
+    #[inline]
+    pub fn elements(&self) -> impl Iterator<Item = Expr> {
+        self.0.children().filter_map(Expr::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AssignExpr(SyntaxNode);
+
+impl AssignExpr {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::AssignExpr {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    /// Returns what’s being assigned to, which has to be a place, e.g. a variable.
+    #[inline]
+    pub fn target(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    #[inline]
+    pub fn value(&self) -> Option<Expr> {
+        self.0.children().filter_map(Expr::cast).nth(1)
+    }
+
+    /// Returns the ‘=’, or the operator of a compound assignment, e.g. ‘+=’.
+    #[inline]
+    pub fn op(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| {
+                matches!(
+                    token.kind(),
+                    SyntaxKind::Equals
+                        | SyntaxKind::PlusEquals
+                        | SyntaxKind::MinusEquals
+                        | SyntaxKind::StarEquals
+                        | SyntaxKind::SlashEquals,
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BinaryExpr(SyntaxNode);
+
+impl BinaryExpr {
+    #[inline]
+    pub fn lhs(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    #[inline]
+    pub fn rhs(&self) -> Option<Expr> {
+        self.0.children().filter_map(Expr::cast).nth(1)
+    }
+
+    #[inline]
+    pub fn op(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| {
+                matches!(
+                    token.kind(),
+                    SyntaxKind::Plus
+                        | SyntaxKind::Minus
+                        | SyntaxKind::Star
+                        | SyntaxKind::Slash
+                        | SyntaxKind::EqualsEquals
+                        | SyntaxKind::BangEquals
+                        | SyntaxKind::Less
+                        | SyntaxKind::LessEquals
+                        | SyntaxKind::Greater
+                        | SyntaxKind::GreaterEquals
+                        | SyntaxKind::AndAnd
+                        | SyntaxKind::OrOr,
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BlockExpr(SyntaxNode);
+
+impl BlockExpr {
+    /// Returns every statement in the block except for its tail expression.
+    #[inline]
+    pub fn stmts(&self) -> impl Iterator<Item = Stmt> {
+        let tail_expr = self.tail_expr_node();
+
+        self.0
+            .children()
+            .filter(move |node| Some(node) != tail_expr.as_ref())
+            .filter_map(Stmt::cast)
+    }
+
+    /// Returns the expression the block evaluates to, i.e. a final expression statement that
+    /// isn’t followed by a ‘;’.
+    #[inline]
+    pub fn tail_expr(&self) -> Option<Expr> {
+        self.tail_expr_node().and_then(Expr::cast)
+    }
+
+    fn tail_expr_node(&self) -> Option<SyntaxNode> {
+        self.0
+            .children_with_tokens()
+            .filter(|element| !element.kind().is_trivia() && element.kind() != SyntaxKind::RBrace)
+            .last()?
+            .into_node()
+            .filter(|node| Expr::cast(node.clone()).is_some())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct BreakExpr(SyntaxNode);
+
+impl BreakExpr {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::BreakExpr {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn value(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CallExpr(SyntaxNode);
+
+impl CallExpr {
+    #[inline]
+    pub fn callee(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    #[inline]
+    pub fn arg_list(&self) -> Option<ArgList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::ArgList)
+            .map(ArgList)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ArgList(SyntaxNode);
+
+impl ArgList {
+    #[inline]
+    pub fn args(&self) -> impl Iterator<Item = Expr> {
+        self.0.children().filter_map(Expr::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ClosureExpr(SyntaxNode);
+
+impl ClosureExpr {
+    #[inline]
+    pub fn param_list(&self) -> Option<ParamList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::ParamList)
+            .map(ParamList)
+    }
+
+    #[inline]
+    pub fn body(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ContinueExpr(SyntaxNode);
+
+impl ContinueExpr {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::ContinueExpr {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FieldExpr(SyntaxNode);
+
+impl FieldExpr {
+    #[inline]
+    pub fn base(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    /// Returns the name after the ‘.’, e.g. the `x` in `p.x`.
+    #[inline]
+    pub fn field(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::Ident)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IfExpr(SyntaxNode);
+
+impl IfExpr {
+    #[inline]
+    pub fn condition(&self) -> Option<Expr> {
+        let then_branch = self.then_branch().map(|then_branch| then_branch.0);
+
+        self.0
+            .children()
+            .take_while(|node| Some(node) != then_branch.as_ref())
+            .find_map(Expr::cast)
+    }
+
+    /// Returns the block that runs when the condition holds. Since the condition can be a block
+    /// itself, this is the last block before ‘else’.
+    #[inline]
+    pub fn then_branch(&self) -> Option<BlockExpr> {
+        self.0
+            .children_with_tokens()
+            .take_while(|element| element.kind() != SyntaxKind::ElseKw)
+            .filter_map(SyntaxElement::into_node)
+            .filter(|node| node.kind() == SyntaxKind::BlockExpr)
+            .last()
+            .map(BlockExpr)
+    }
+
+    #[inline]
+    pub fn else_kw(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|token| token.kind() == SyntaxKind::ElseKw)
+    }
+
+    /// Returns the block or the chained ‘if’ after ‘else’.
+    #[inline]
+    pub fn else_branch(&self) -> Option<Expr> {
+        self.0
+            .children_with_tokens()
+            .skip_while(|element| element.kind() != SyntaxKind::ElseKw)
+            .filter_map(SyntaxElement::into_node)
+            .find_map(Expr::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IndexExpr(SyntaxNode);
+
+impl IndexExpr {
+    /// Returns what’s being indexed, e.g. the `xs` in `xs[i]`.
+    #[inline]
+    pub fn base(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
+    }
+
+    #[inline]
+    pub fn index(&self) -> Option<Expr> {
+        self.0.children().filter_map(Expr::cast).nth(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Literal(SyntaxNode);
+
+impl Literal {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        if node.kind() == SyntaxKind::Literal {
+            Some(Self(node))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn kind(&self) -> LiteralKind {
+        match self.token().kind() {
+            SyntaxKind::Number => LiteralKind::Number,
+            SyntaxKind::Float => LiteralKind::Float,
+            SyntaxKind::String => LiteralKind::String,
+            SyntaxKind::TrueKw | SyntaxKind::FalseKw => LiteralKind::Bool,
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline]
+    pub fn token(&self) -> SyntaxToken {
+        first_token(&self.0).unwrap()
+    }
+
+    /// Returns the value of a number literal, or `None` if this isn’t a number literal, the number
+    /// is malformed (e.g. `0b102` or `0x`) or it’s too big to fit in a u64.
+    #[inline]
+    pub fn parse(&self) -> Option<u64> {
+        match self.kind() {
+            LiteralKind::Number => {
+                let token = self.token();
+                let (radix, digits) = split_radix(token.text());
+                let digits: String = digits.chars().filter(|c| *c != '_').collect();
+
+                u64::from_str_radix(&digits, radix).ok()
+            }
+            LiteralKind::Float | LiteralKind::String | LiteralKind::Bool => None,
+        }
+    }
+
+    /// Returns the value of a float literal, or `None` if this isn’t a float literal or its exponent
+    /// is malformed, e.g. `1e+`.
+    #[inline]
+    pub fn parse_float(&self) -> Option<f64> {
+        if self.kind() != LiteralKind::Float {
+            return None;
+        }
+
+        let token = self.token();
+        let text: String = token.text().chars().filter(|c| *c != '_').collect();
+
+        text.parse().ok()
+    }
+
+    /// Returns the value of a boolean literal, or `None` if this isn’t a boolean literal.
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.token().kind() {
+            SyntaxKind::TrueKw => Some(true),
+            SyntaxKind::FalseKw => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of a string literal with its escape sequences resolved, or `None` if this
+    /// isn’t a string literal or the string is malformed.
+    pub fn unescape(&self) -> Option<String> {
+        if self.kind() != LiteralKind::String {
+            return None;
+        }
+
+        let token = self.token();
+        let contents = lexer::string_contents(token.text())?;
+
+        let mut is_valid = true;
+        let value = lexer::unescape(contents, |_, _| is_valid = false);
+
+        is_valid.then_some(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiteralKind {
+    /// an integer literal, e.g. `42` or `0xFF`
+    Number,
+    Float,
+    String,
+    Bool,
+}
+
+/// Splits a number literal’s text into its radix and the digits following the radix prefix, e.g.
+/// `0xFF_FF` becomes `(16, "FF_FF")`. The digits may still contain `_` separators.
+pub(crate) fn split_radix(text: &str) -> (u32, &str) {
+    let radix = match text.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0o" | "0O") => 8,
+        Some("0b" | "0B") => 2,
+        _ => return (10, text),
+    };
+
+    (radix, &text[2..])
+}
+
+/// Returns the exponent of a float literal’s text, starting at its `e` or `E`, along with the
+/// exponent’s offset into the text.
+pub(crate) fn float_exponent(text: &str) -> Option<(usize, &str)> {
+    let idx = text.find(['e', 'E'])?;
+    Some((idx, &text[idx..]))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LoopExpr(SyntaxNode);
+
+impl LoopExpr {
     #[inline]
-    pub fn name(&self) -> Option<SyntaxToken> {
+    pub fn body(&self) -> Option<BlockExpr> {
         self.0
-            .children_with_tokens()
-            .filter_map(SyntaxElement::into_token)
-            .find(|token| token.kind() == SyntaxKind::Ident)
+            .children()
+            .find(|node| node.kind() == SyntaxKind::BlockExpr)
+            .map(BlockExpr)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MatchExpr(SyntaxNode);
 
+impl MatchExpr {
+    /// Returns the expression being matched on.
     #[inline]
-    pub fn value(&self) -> Option<Expr> {
+    pub fn scrutinee(&self) -> Option<Expr> {
         self.0.children().find_map(Expr::cast)
     }
+
+    #[inline]
+    pub fn arm_list(&self) -> Option<MatchArmList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::MatchArmList)
+            .map(MatchArmList)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Expr {
-    BinaryExpr(BinaryExpr),
-    Literal(Literal),
-    ParenExpr(ParenExpr),
-    UnaryExpr(UnaryExpr),
-    VariableRef(VariableRef),
+#[repr(transparent)]
+pub struct MatchArmList(SyntaxNode);
+
+impl MatchArmList {
+    #[inline]
+    pub fn arms(&self) -> impl Iterator<Item = MatchArm> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::MatchArm)
+            .map(MatchArm)
+    }
 }
 
-impl Expr {
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct MatchArm(SyntaxNode);
+
+impl MatchArm {
     #[inline]
-    pub fn cast(node: SyntaxNode) -> Option<Self> {
-        let result = match node.kind() {
-            SyntaxKind::InfixExpr => Self::BinaryExpr(BinaryExpr(node)),
-            SyntaxKind::Literal => Self::Literal(Literal(node)),
-            SyntaxKind::ParenExpr => Self::ParenExpr(ParenExpr(node)),
-            SyntaxKind::PrefixExpr => Self::UnaryExpr(UnaryExpr(node)),
-            SyntaxKind::VariableRef => Self::VariableRef(VariableRef(node)),
-            _ => return None,
-        };
+    pub fn pat(&self) -> Option<Pat> {
+        self.0.children().find_map(Pat::cast)
+    }
 
-        Some(result)
+    #[inline]
+    pub fn guard(&self) -> Option<MatchGuard> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::MatchGuard)
+            .map(MatchGuard)
+    }
+
+    /// Returns the expression after the ‘=>’.
+    #[inline]
+    pub fn body(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
-pub struct BinaryExpr(SyntaxNode);
+pub struct MatchGuard(SyntaxNode);
 
-impl BinaryExpr {
+impl MatchGuard {
     #[inline]
-    pub fn lhs(&self) -> Option<Expr> {
+    pub fn condition(&self) -> Option<Expr> {
         self.0.children().find_map(Expr::cast)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParenExpr(SyntaxNode);
 
+impl ParenExpr {
     #[inline]
-    pub fn rhs(&self) -> Option<Expr> {
-        self.0.children().filter_map(Expr::cast).nth(1)
+    pub fn expr(&self) -> Option<Expr> {
+        self.0.children().find_map(Expr::cast)
     }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct PathExpr(SyntaxNode);
 
+impl PathExpr {
     #[inline]
-    pub fn op(&self) -> Option<SyntaxToken> {
+    pub fn path(&self) -> Option<Path> {
         self.0
-            .children_with_tokens()
-            .filter_map(SyntaxElement::into_token)
-            .find(|token| {
-                matches!(
-                    token.kind(),
-                    SyntaxKind::Plus | SyntaxKind::Minus | SyntaxKind::Star | SyntaxKind::Slash,
-                )
-            })
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Path)
+            .map(Path)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
-pub struct Literal(SyntaxNode);
+pub struct RecordExpr(SyntaxNode);
 
-impl Literal {
+impl RecordExpr {
+    /// Returns the name of the struct being constructed, e.g. the `Point` in `Point { x: 1 }`.
     #[inline]
-    pub fn cast(node: SyntaxNode) -> Option<Self> {
-        if node.kind() == SyntaxKind::Literal {
-            Some(Self(node))
-        } else {
-            None
-        }
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
     }
 
     #[inline]
-    pub fn parse(&self) -> Option<u64> {
-        self.0.first_token().unwrap().text().parse().ok()
+    pub fn field_list(&self) -> Option<RecordFieldList> {
+        self.0
+            .children()
+            .find(|node| node.kind() == SyntaxKind::RecordFieldList)
+            .map(RecordFieldList)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
-pub struct ParenExpr(SyntaxNode);
+pub struct RecordFieldList(SyntaxNode);
 
-impl ParenExpr {
+impl RecordFieldList {
     #[inline]
-    pub fn expr(&self) -> Option<Expr> {
+    pub fn fields(&self) -> impl Iterator<Item = RecordField> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::RecordField)
+            .map(RecordField)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct RecordField(SyntaxNode);
+
+impl RecordField {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+
+    #[inline]
+    pub fn value(&self) -> Option<Expr> {
         self.0.children().find_map(Expr::cast)
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TupleExpr(SyntaxNode);
+
+impl TupleExpr {
+    #[inline]
+    pub fn fields(&self) -> impl Iterator<Item = Expr> {
+        self.0.children().filter_map(Expr::cast)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct UnaryExpr(SyntaxNode);
@@ -161,7 +1112,7 @@ impl UnaryExpr {
         self.0
             .children_with_tokens()
             .filter_map(SyntaxElement::into_token)
-            .find(|token| token.kind() == SyntaxKind::Minus)
+            .find(|token| matches!(token.kind(), SyntaxKind::Minus | SyntaxKind::Bang))
     }
 }
 
@@ -172,6 +1123,376 @@ pub struct VariableRef(SyntaxNode);
 impl VariableRef {
     #[inline]
     pub fn name(&self) -> Option<SyntaxToken> {
-        self.0.first_token()
+        first_token(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct WhileExpr(SyntaxNode);
+
+impl WhileExpr {
+    #[inline]
+    pub fn condition(&self) -> Option<Expr> {
+        let body = self.body().map(|body| body.0);
+
+        self.0
+            .children()
+            .take_while(|node| Some(node) != body.as_ref())
+            .find_map(Expr::cast)
+    }
+
+    /// Returns the loop’s body. Since the condition can be a block itself, this is the last block.
+    #[inline]
+    pub fn body(&self) -> Option<BlockExpr> {
+        self.0
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::BlockExpr)
+            .last()
+            .map(BlockExpr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pat {
+    IdentPat(IdentPat),
+    LiteralPat(LiteralPat),
+    ParenPat(ParenPat),
+    TuplePat(TuplePat),
+    VariantPat(VariantPat),
+}
+
+impl Pat {
+    #[inline]
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        let result = match node.kind() {
+            SyntaxKind::IdentPat => Self::IdentPat(IdentPat(node)),
+            SyntaxKind::LiteralPat => Self::LiteralPat(LiteralPat(node)),
+            SyntaxKind::ParenPat => Self::ParenPat(ParenPat(node)),
+            SyntaxKind::TuplePat => Self::TuplePat(TuplePat(node)),
+            SyntaxKind::VariantPat => Self::VariantPat(VariantPat(node)),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+}
+
+/// A name on its own, which binds whatever it’s matched against, e.g. `x`. The name `_` is a
+/// wildcard instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct IdentPat(SyntaxNode);
+
+impl IdentPat {
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct LiteralPat(SyntaxNode);
+
+impl LiteralPat {
+    #[inline]
+    pub fn literal(&self) -> Option<Literal> {
+        self.0.children().find_map(Literal::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ParenPat(SyntaxNode);
+
+impl ParenPat {
+    #[inline]
+    pub fn pat(&self) -> Option<Pat> {
+        self.0.children().find_map(Pat::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TuplePat(SyntaxNode);
+
+impl TuplePat {
+    #[inline]
+    pub fn fields(&self) -> impl Iterator<Item = Pat> {
+        self.0.children().filter_map(Pat::cast)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct VariantPat(SyntaxNode);
+
+impl VariantPat {
+    /// Returns the name of the variant being matched, e.g. the `Rect` in `Rect(w, h)`.
+    #[inline]
+    pub fn name(&self) -> Option<SyntaxToken> {
+        first_token(&self.0)
+    }
+
+    #[inline]
+    pub fn fields(&self) -> impl Iterator<Item = Pat> {
+        self.0.children().filter_map(Pat::cast)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_docs(input: &str, expected_docs: Option<&str>) {
+        let root = Root::cast(parser::parse(input).syntax()).unwrap();
+
+        let docs = match root.stmts().last().unwrap() {
+            Stmt::FnDef(fn_def) => fn_def.docs(),
+            Stmt::EnumDef(enum_def) => enum_def.docs(),
+            Stmt::ModDef(mod_def) => mod_def.docs(),
+            Stmt::StructDef(struct_def) => struct_def.docs(),
+            Stmt::VariableDef(variable_def) => variable_def.docs(),
+            Stmt::UseDef(_) | Stmt::Expr(_) => unreachable!(),
+        };
+
+        assert_eq!(docs.as_deref(), expected_docs);
+    }
+
+    #[test]
+    fn variable_def_docs() {
+        check_docs("## The answer.\nlet a = 42", Some("The answer."));
+    }
+
+    #[test]
+    fn multi_line_fn_def_docs() {
+        check_docs(
+            "let a = 1\n## Adds two numbers.\n##\n##   Indented.\nfn add(a, b) { a + b }",
+            Some("Adds two numbers.\n\n  Indented."),
+        );
+    }
+
+    #[test]
+    fn docs_of_nested_fn_def() {
+        let root = Root::cast(parser::parse("{\n  ## Nested.\n  fn f() {}\n}").syntax()).unwrap();
+
+        let Some(Stmt::Expr(Expr::BlockExpr(block))) = root.stmts().next() else {
+            panic!("expected a block");
+        };
+
+        let Some(Stmt::FnDef(fn_def)) = block.stmts().next() else {
+            panic!("expected a function definition");
+        };
+
+        assert_eq!(fn_def.docs().as_deref(), Some("Nested."));
+    }
+
+    #[test]
+    fn struct_def_docs() {
+        check_docs("## A point.\nstruct Point { x: Int }", Some("A point."));
+    }
+
+    #[test]
+    fn mod_def_docs() {
+        check_docs(
+            "## Shapes and their areas.\nmod shapes",
+            Some("Shapes and their areas."),
+        );
+    }
+
+    #[test]
+    fn no_docs() {
+        check_docs("# Just a comment.\nlet a = 1", None);
+    }
+
+    #[test]
+    fn docs_separated_by_comment() {
+        check_docs("## Detached.\n# comment\nfn f() {}", None);
+    }
+
+    #[test]
+    fn cast_expr_fragment() {
+        let parse = parser::parse_expr("1 + 2");
+        assert!(matches!(
+            Expr::cast(parse.syntax()),
+            Some(Expr::BinaryExpr(_))
+        ));
+    }
+
+    #[test]
+    fn cast_stmt_fragment() {
+        let parse = parser::parse_stmt("fn f() {}");
+        assert!(matches!(Stmt::cast(parse.syntax()), Some(Stmt::FnDef(_))));
+    }
+
+    #[test]
+    fn cast_expr_fragment_with_trailing_input() {
+        let parse = parser::parse_expr("1 + 2 3");
+        assert!(Expr::cast(parse.syntax()).is_none());
+    }
+
+    #[test]
+    fn struct_def_fields() {
+        let parse = parser::parse_stmt("struct Point { x: Int, y }");
+        let Some(Stmt::StructDef(struct_def)) = Stmt::cast(parse.syntax()) else {
+            panic!("expected a struct definition");
+        };
+
+        assert_eq!(struct_def.name().unwrap().text(), "Point");
+
+        let fields: Vec<_> = struct_def.field_list().unwrap().fields().collect();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name().unwrap().text(), "x");
+        assert_eq!(fields[0].ty().unwrap().name().unwrap().text(), "Int");
+        assert_eq!(fields[1].name().unwrap().text(), "y");
+        assert!(fields[1].ty().is_none());
+    }
+
+    #[test]
+    fn path_type_ref_segments() {
+        let parse = parser::parse_type("shapes::Shape");
+        let type_ref = TypeRef::cast(parse.syntax()).unwrap();
+        assert_eq!(type_ref.kind(), TypeRefKind::Path);
+
+        let segments: Vec<_> = type_ref.path().unwrap().segments().collect();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text(), "shapes");
+        assert_eq!(segments[1].text(), "Shape");
+    }
+
+    #[test]
+    fn record_expr_fields() {
+        let parse = parser::parse_expr("Point { x: 1, y: a.b }");
+        let Some(Expr::RecordExpr(record_expr)) = Expr::cast(parse.syntax()) else {
+            panic!("expected a record expression");
+        };
+
+        assert_eq!(record_expr.name().unwrap().text(), "Point");
+
+        let fields: Vec<_> = record_expr.field_list().unwrap().fields().collect();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name().unwrap().text(), "x");
+        assert!(matches!(fields[0].value(), Some(Expr::Literal(_))));
+        assert_eq!(fields[1].name().unwrap().text(), "y");
+
+        let Some(Expr::FieldExpr(field_expr)) = fields[1].value() else {
+            panic!("expected a field access");
+        };
+
+        assert!(matches!(field_expr.base(), Some(Expr::VariableRef(_))));
+        assert_eq!(field_expr.field().unwrap().text(), "b");
+    }
+
+    #[test]
+    fn enum_def_variants() {
+        let parse = parser::parse_stmt("enum Shape { Circle(r), Empty }");
+        let Some(Stmt::EnumDef(enum_def)) = Stmt::cast(parse.syntax()) else {
+            panic!("expected an enum definition");
+        };
+
+        assert_eq!(enum_def.name().unwrap().text(), "Shape");
+
+        let variants: Vec<_> = enum_def.variant_list().unwrap().variants().collect();
+        assert_eq!(variants.len(), 2);
+        assert_eq!(variants[0].name().unwrap().text(), "Circle");
+        assert_eq!(variants[0].param_list().unwrap().params().count(), 1);
+        assert_eq!(variants[1].name().unwrap().text(), "Empty");
+        assert!(variants[1].param_list().is_none());
+    }
+
+    #[test]
+    fn match_arms() {
+        let parse = parser::parse_expr("match s { Rect(w, _) if w > 0 => w, (1, x) => x }");
+        let Some(Expr::MatchExpr(match_expr)) = Expr::cast(parse.syntax()) else {
+            panic!("expected a match expression");
+        };
+
+        assert!(matches!(match_expr.scrutinee(), Some(Expr::VariableRef(_))));
+
+        let arms: Vec<_> = match_expr.arm_list().unwrap().arms().collect();
+        assert_eq!(arms.len(), 2);
+
+        let Some(Pat::VariantPat(variant_pat)) = arms[0].pat() else {
+            panic!("expected a variant pattern");
+        };
+
+        assert_eq!(variant_pat.name().unwrap().text(), "Rect");
+        assert_eq!(variant_pat.fields().count(), 2);
+
+        let guard = arms[0].guard().unwrap();
+        assert!(matches!(guard.condition(), Some(Expr::BinaryExpr(_))));
+        assert!(matches!(arms[0].body(), Some(Expr::VariableRef(_))));
+
+        let Some(Pat::TuplePat(tuple_pat)) = arms[1].pat() else {
+            panic!("expected a tuple pattern");
+        };
+
+        let fields: Vec<_> = tuple_pat.fields().collect();
+        assert!(
+            matches!(&fields[0], Pat::LiteralPat(literal_pat) if literal_pat.literal().is_some())
+        );
+        assert!(matches!(&fields[1], Pat::IdentPat(_)));
+        assert!(arms[1].guard().is_none());
+    }
+
+    #[test]
+    fn closure_params_and_body() {
+        let parse = parser::parse_expr("|x, y: Int| x + y");
+        let Some(Expr::ClosureExpr(closure_expr)) = Expr::cast(parse.syntax()) else {
+            panic!("expected a closure");
+        };
+
+        let params: Vec<_> = closure_expr.param_list().unwrap().params().collect();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].name().unwrap().text(), "x");
+        assert!(params[0].ty().is_none());
+        assert_eq!(params[1].name().unwrap().text(), "y");
+        assert!(params[1].ty().is_some());
+        assert!(matches!(closure_expr.body(), Some(Expr::BinaryExpr(_))));
+    }
+
+    #[test]
+    fn fn_closure_params_and_body() {
+        let parse = parser::parse_expr("fn(x) => x * 2");
+        let Some(Expr::ClosureExpr(closure_expr)) = Expr::cast(parse.syntax()) else {
+            panic!("expected a closure");
+        };
+
+        let params: Vec<_> = closure_expr.param_list().unwrap().params().collect();
+        assert_eq!(params.len(), 1);
+        assert_eq!(params[0].name().unwrap().text(), "x");
+        assert!(matches!(closure_expr.body(), Some(Expr::BinaryExpr(_))));
+    }
+
+    #[test]
+    fn mod_def_name() {
+        let parse = parser::parse_stmt("mod shapes");
+        let Some(Stmt::ModDef(mod_def)) = Stmt::cast(parse.syntax()) else {
+            panic!("expected a module declaration");
+        };
+
+        assert_eq!(mod_def.mod_kw().unwrap().text(), "mod");
+        assert_eq!(mod_def.name().unwrap().text(), "shapes");
+    }
+
+    #[test]
+    fn use_def_and_path_expr_segments() {
+        let parse = parser::parse_stmt("use shapes::area");
+        let Some(Stmt::UseDef(use_def)) = Stmt::cast(parse.syntax()) else {
+            panic!("expected a use declaration");
+        };
+
+        let segments: Vec<_> = use_def.path().unwrap().segments().collect();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].text(), "shapes");
+        assert_eq!(segments[1].text(), "area");
+
+        let parse = parser::parse_expr("a::b::c");
+        let Some(Expr::PathExpr(path_expr)) = Expr::cast(parse.syntax()) else {
+            panic!("expected a path expression");
+        };
+
+        assert_eq!(path_expr.path().unwrap().segments().count(), 3);
     }
 }
@@ -1,7 +1,10 @@
-use crate::Literal;
+use crate::{
+    AssignExpr, BreakExpr, ContinueExpr, Expr, Literal, LiteralKind, TypeRef, TypeRefKind,
+    VariableDef,
+};
 use std::fmt;
-use syntax::SyntaxNode;
-use text_size::TextRange;
+use syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+use text_size::{TextRange, TextSize};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidationError {
@@ -24,6 +27,13 @@ impl fmt::Display for ValidationError {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ValidationErrorKind {
     NumberLiteralTooLarge,
+    InvalidDigit { radix: u32 },
+    EmptyNumberLiteral,
+    MalformedExponent,
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    InvalidAssignTarget,
+    OneElementTupleWithoutTrailingComma,
 }
 
 impl fmt::Display for ValidationErrorKind {
@@ -34,6 +44,19 @@ impl fmt::Display for ValidationErrorKind {
                 "number literal is larger than an integer’s maximum value, {}",
                 u64::MAX,
             ),
+            Self::InvalidDigit { radix } => {
+                write!(f, "invalid digit for a base {radix} number literal")
+            }
+            Self::EmptyNumberLiteral => write!(f, "number literal has no digits"),
+            Self::MalformedExponent => write!(f, "exponent has no digits"),
+            Self::BreakOutsideLoop => write!(f, "‘break’ outside of a loop"),
+            Self::ContinueOutsideLoop => write!(f, "‘continue’ outside of a loop"),
+            Self::InvalidAssignTarget => {
+                write!(f, "can only assign to a variable, element or field")
+            }
+            Self::OneElementTupleWithoutTrailingComma => {
+                write!(f, "one-element tuple needs a trailing comma")
+            }
         }
     }
 }
@@ -42,8 +65,26 @@ pub fn validate(node: &SyntaxNode) -> Vec<ValidationError> {
     let mut errors = Vec::new();
 
     for node in node.descendants() {
-        if let Some(literal) = Literal::cast(node) {
+        if let Some(literal) = Literal::cast(node.clone()) {
             validate_literal(&literal, &mut errors);
+        } else if let Some(break_expr) = BreakExpr::cast(node.clone()) {
+            validate_in_loop(
+                &break_expr.0,
+                ValidationErrorKind::BreakOutsideLoop,
+                &mut errors,
+            );
+        } else if let Some(continue_expr) = ContinueExpr::cast(node.clone()) {
+            validate_in_loop(
+                &continue_expr.0,
+                ValidationErrorKind::ContinueOutsideLoop,
+                &mut errors,
+            );
+        } else if let Some(assign_expr) = AssignExpr::cast(node.clone()) {
+            validate_assign_target(&assign_expr, &mut errors);
+        } else if let Some(variable_def) = VariableDef::cast(node)
+            && let (Some(ty), Some(value)) = (variable_def.ty(), variable_def.value())
+        {
+            validate_tuple_value(&ty, &value, &mut errors);
         }
     }
 
@@ -51,22 +92,216 @@ pub fn validate(node: &SyntaxNode) -> Vec<ValidationError> {
 }
 
 fn validate_literal(literal: &Literal, errors: &mut Vec<ValidationError>) {
-    if literal.parse().is_none() {
+    match literal.kind() {
+        LiteralKind::Number => validate_number(literal, errors),
+        LiteralKind::Float => validate_float(&literal.token(), errors),
+        // Malformed strings are reported by the lexer.
+        LiteralKind::String | LiteralKind::Bool => {}
+    }
+}
+
+fn validate_number(literal: &Literal, errors: &mut Vec<ValidationError>) {
+    let token = literal.token();
+    let range = token.text_range();
+    let (radix, digits) = crate::split_radix(token.text());
+    let digits_start = range.end() - TextSize::of(digits);
+
+    let mut has_digits = false;
+    let mut has_invalid_digits = false;
+
+    for (idx, c) in digits.char_indices() {
+        if c == '_' {
+            continue;
+        }
+
+        has_digits = true;
+
+        if !c.is_digit(radix) {
+            has_invalid_digits = true;
+            errors.push(ValidationError {
+                kind: ValidationErrorKind::InvalidDigit { radix },
+                range: TextRange::at(
+                    digits_start + TextSize::try_from(idx).unwrap(),
+                    TextSize::of(c),
+                ),
+            });
+        }
+    }
+
+    if !has_digits {
+        errors.push(ValidationError {
+            kind: ValidationErrorKind::EmptyNumberLiteral,
+            range,
+        });
+    } else if !has_invalid_digits && literal.parse().is_none() {
         errors.push(ValidationError {
             kind: ValidationErrorKind::NumberLiteralTooLarge,
-            range: literal.0.first_token().unwrap().text_range(),
+            range,
         });
     }
 }
 
+fn validate_float(token: &SyntaxToken, errors: &mut Vec<ValidationError>) {
+    let Some((idx, exponent)) = crate::float_exponent(token.text()) else {
+        return;
+    };
+
+    if !exponent.chars().any(|c| c.is_ascii_digit()) {
+        errors.push(ValidationError {
+            kind: ValidationErrorKind::MalformedExponent,
+            range: TextRange::at(
+                token.text_range().start() + TextSize::try_from(idx).unwrap(),
+                TextSize::of(exponent),
+            ),
+        });
+    }
+}
+
+fn validate_in_loop(
+    node: &SyntaxNode,
+    kind: ValidationErrorKind,
+    errors: &mut Vec<ValidationError>,
+) {
+    // A function or closure body starts a fresh context, so loops outside of it don’t count.
+    let in_loop = node
+        .ancestors()
+        .skip(1)
+        .take_while(|ancestor| {
+            !matches!(ancestor.kind(), SyntaxKind::FnDef | SyntaxKind::ClosureExpr)
+        })
+        .any(|ancestor| {
+            matches!(
+                ancestor.kind(),
+                SyntaxKind::LoopExpr | SyntaxKind::WhileExpr
+            )
+        });
+
+    if !in_loop {
+        errors.push(ValidationError {
+            kind,
+            range: crate::first_token(node).unwrap().text_range(),
+        });
+    }
+}
+
+fn validate_assign_target(assign_expr: &AssignExpr, errors: &mut Vec<ValidationError>) {
+    // A missing target has already been reported by the parser.
+    let Some(target) = assign_expr.target() else {
+        return;
+    };
+
+    if !is_place(&target) {
+        errors.push(ValidationError {
+            kind: ValidationErrorKind::InvalidAssignTarget,
+            range: trimmed_range(target.syntax()),
+        });
+    }
+}
+
+/// Checks that a value written in parentheses is a tuple wherever its type says it has to be one,
+/// and the other way around, e.g. `let a: (Int,) = (1)`, which meant `(1,)`, or
+/// `let a: (Int) = (1,)`, which meant `(Int,)`. Since a comma is what makes a tuple, one with a
+/// single field is easy to write as plain parentheses by mistake. Parentheses anywhere else are
+/// just grouping, so they’re left alone.
+fn validate_tuple_value(ty: &TypeRef, value: &Expr, errors: &mut Vec<ValidationError>) {
+    let (inner_ty, ty_has_parens) = strip_paren_types(ty.clone());
+    let (inner_value, value_has_parens) = strip_paren_exprs(value.clone());
+
+    let range = match (inner_ty.kind(), &inner_value) {
+        (TypeRefKind::Tuple, Expr::TupleExpr(tuple_expr)) => {
+            if inner_ty.fields().count() == tuple_expr.fields().count() {
+                for (ty, value) in inner_ty.fields().zip(tuple_expr.fields()) {
+                    validate_tuple_value(&ty, &value, errors);
+                }
+            }
+
+            return;
+        }
+        (TypeRefKind::Tuple, _) if value_has_parens && inner_ty.fields().count() == 1 => {
+            trimmed_range(value.syntax())
+        }
+        (_, Expr::TupleExpr(tuple_expr)) if ty_has_parens && tuple_expr.fields().count() == 1 => {
+            trimmed_range(&ty.0)
+        }
+        _ => return,
+    };
+
+    errors.push(ValidationError {
+        kind: ValidationErrorKind::OneElementTupleWithoutTrailingComma,
+        range,
+    });
+}
+
+/// Returns the type inside any parentheses around it, along with whether there were any.
+fn strip_paren_types(mut ty: TypeRef) -> (TypeRef, bool) {
+    let mut has_parens = false;
+
+    while ty.kind() == TypeRefKind::Paren {
+        let Some(inner) = ty.fields().next() else {
+            break;
+        };
+
+        ty = inner;
+        has_parens = true;
+    }
+
+    (ty, has_parens)
+}
+
+/// Returns the expression inside any parentheses around it, along with whether there were any.
+fn strip_paren_exprs(mut expr: Expr) -> (Expr, bool) {
+    let mut has_parens = false;
+
+    while let Expr::ParenExpr(paren_expr) = &expr {
+        let Some(inner) = paren_expr.expr() else {
+            break;
+        };
+
+        expr = inner;
+        has_parens = true;
+    }
+
+    (expr, has_parens)
+}
+
+/// Returns whether an expression refers to somewhere a value can be stored.
+fn is_place(expr: &Expr) -> bool {
+    match expr {
+        Expr::VariableRef(_) | Expr::IndexExpr(_) | Expr::FieldExpr(_) => true,
+        Expr::ParenExpr(paren_expr) => paren_expr.expr().is_some_and(|expr| is_place(&expr)),
+        _ => false,
+    }
+}
+
+/// Returns the range of a node without any trivia at either end.
+fn trimmed_range(node: &SyntaxNode) -> TextRange {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|token| !token.kind().is_trivia());
+
+    let Some(first) = tokens.next() else {
+        return node.text_range();
+    };
+
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+
+    TextRange::new(first.text_range().start(), last.text_range().end())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::ops::Range as StdRange;
 
     fn check(input: &str, expected_errors: &[(ValidationErrorKind, StdRange<u32>)]) {
-        let parse = parser::parse(input);
+        check_parse(&parser::parse(input), expected_errors);
+    }
 
+    fn check_parse(
+        parse: &parser::Parse,
+        expected_errors: &[(ValidationErrorKind, StdRange<u32>)],
+    ) {
         let expected_errors: Vec<_> = expected_errors
             .iter()
             .map(|(kind, range)| ValidationError {
@@ -94,4 +329,219 @@ mod tests {
             &[(ValidationErrorKind::NumberLiteralTooLarge, (0..20))],
         );
     }
+
+    #[test]
+    fn validate_ok_literals_with_radix_and_separators() {
+        check("1_000 + 0xFF_ff + 0o755 + 0b1010_1010", &[]);
+    }
+
+    #[test]
+    fn validate_too_large_hexadecimal_literal() {
+        check(
+            "1 + 0x1_0000_0000_0000_0000",
+            &[(ValidationErrorKind::NumberLiteralTooLarge, (4..27))],
+        );
+    }
+
+    #[test]
+    fn validate_largest_binary_literal() {
+        check(&format!("0b{}", "1".repeat(64)), &[]);
+    }
+
+    #[test]
+    fn validate_invalid_digits() {
+        check(
+            "0b1021 + 0o78",
+            &[
+                (ValidationErrorKind::InvalidDigit { radix: 2 }, (4..5)),
+                (ValidationErrorKind::InvalidDigit { radix: 8 }, (12..13)),
+            ],
+        );
+    }
+
+    #[test]
+    fn validate_invalid_hexadecimal_digits() {
+        check(
+            "0xFG_H1",
+            &[
+                (ValidationErrorKind::InvalidDigit { radix: 16 }, (3..4)),
+                (ValidationErrorKind::InvalidDigit { radix: 16 }, (5..6)),
+            ],
+        );
+    }
+
+    #[test]
+    fn validate_empty_literal() {
+        check("0x", &[(ValidationErrorKind::EmptyNumberLiteral, (0..2))]);
+    }
+
+    #[test]
+    fn validate_literal_with_only_separators() {
+        check("0b__", &[(ValidationErrorKind::EmptyNumberLiteral, (0..4))]);
+    }
+
+    #[test]
+    fn validate_ok_floats() {
+        check("3.14 + 1e-9 + 2.5E3 + 1_000.5e1_0", &[]);
+    }
+
+    #[test]
+    fn validate_exponent_without_digits() {
+        check(
+            "1 + 1.5e",
+            &[(ValidationErrorKind::MalformedExponent, (7..8))],
+        );
+    }
+
+    #[test]
+    fn validate_exponent_with_only_sign_and_separators() {
+        check("2E+__", &[(ValidationErrorKind::MalformedExponent, (1..5))]);
+    }
+
+    #[test]
+    fn validate_bool_literals() {
+        check("true == false", &[]);
+    }
+
+    #[test]
+    fn validate_break_inside_loop() {
+        check("loop { break 1 }", &[]);
+    }
+
+    #[test]
+    fn validate_continue_inside_while() {
+        check("while a { if b { continue } }", &[]);
+    }
+
+    #[test]
+    fn validate_break_outside_loop() {
+        check(
+            "let a = 1\nbreak a",
+            &[(ValidationErrorKind::BreakOutsideLoop, (10..15))],
+        );
+    }
+
+    #[test]
+    fn validate_fragments_with_leading_trivia() {
+        check_parse(&parser::parse_expr("  1 + 2"), &[]);
+        check_parse(
+            &parser::parse_expr("# big\n99999999999999999999"),
+            &[(ValidationErrorKind::NumberLiteralTooLarge, (6..26))],
+        );
+        check_parse(
+            &parser::parse_stmt(" break"),
+            &[(ValidationErrorKind::BreakOutsideLoop, (1..6))],
+        );
+    }
+
+    #[test]
+    fn validate_continue_outside_loop() {
+        check(
+            "{ continue }",
+            &[(ValidationErrorKind::ContinueOutsideLoop, (2..10))],
+        );
+    }
+
+    #[test]
+    fn validate_break_in_fn_inside_loop() {
+        check(
+            "loop { fn f() { break } }",
+            &[(ValidationErrorKind::BreakOutsideLoop, (16..21))],
+        );
+    }
+
+    #[test]
+    fn validate_break_in_closure_inside_loop() {
+        check(
+            "loop { f(|| break) }",
+            &[(ValidationErrorKind::BreakOutsideLoop, (12..17))],
+        );
+    }
+
+    #[test]
+    fn validate_break_in_loop_inside_closure() {
+        check("|| loop { break }", &[]);
+    }
+
+    #[test]
+    fn validate_assignments_to_variables() {
+        check("a = 1\n(b) += 2\nc = d = 3", &[]);
+    }
+
+    #[test]
+    fn validate_one_element_tuple_value_without_trailing_comma() {
+        check(
+            "let a: (Int,) = (1)",
+            &[(
+                ValidationErrorKind::OneElementTupleWithoutTrailingComma,
+                (16..19),
+            )],
+        );
+    }
+
+    #[test]
+    fn validate_one_element_tuple_type_without_trailing_comma() {
+        check(
+            "let a: (Int) = (1,)",
+            &[(
+                ValidationErrorKind::OneElementTupleWithoutTrailingComma,
+                (7..12),
+            )],
+        );
+    }
+
+    #[test]
+    fn validate_one_element_tuple_nested_in_tuple() {
+        check(
+            "let a: ((Int,), Bool) = ((1), true)",
+            &[(
+                ValidationErrorKind::OneElementTupleWithoutTrailingComma,
+                (25..28),
+            )],
+        );
+    }
+
+    #[test]
+    fn validate_grouping_parens() {
+        check("let a: (Int) = (1)", &[]);
+        check("let a: (Int,) = ((1,))", &[]);
+        check("let a: ((Int,)) = (1,)", &[]);
+        check("let a: (Int, Bool) = ((1), (true))", &[]);
+        check("let a: (Int,) = b", &[]);
+        check("let a = (1)\nf((a))", &[]);
+    }
+
+    #[test]
+    fn validate_assignments_to_elements() {
+        check("xs[0] = 1\nxs[i][j] -= 2\nf()[0] = 3\n(xs[0]) = 4", &[]);
+    }
+
+    #[test]
+    fn validate_assignments_to_fields() {
+        check("p.x = 1\np.x += 1\nps[0].pos.y *= 2\n(p.x) = 3", &[]);
+    }
+
+    #[test]
+    fn validate_assignment_to_literal() {
+        check(
+            "1 = 2",
+            &[(ValidationErrorKind::InvalidAssignTarget, (0..1))],
+        );
+    }
+
+    #[test]
+    fn validate_assignment_to_binary_expr() {
+        check(
+            "(a + b) = 3",
+            &[(ValidationErrorKind::InvalidAssignTarget, (0..7))],
+        );
+    }
+
+    #[test]
+    fn validate_compound_assignment_to_call() {
+        check(
+            "f(a) *= 2",
+            &[(ValidationErrorKind::InvalidAssignTarget, (0..4))],
+        );
+    }
 }
@@ -1,15 +1,18 @@
 #![deny(clippy::all)]
 
+use lexer::{LexErrorKind, Lexer, TokenKind};
 use mimalloc::MiMalloc;
 use parser::parse;
 use reedline::{
     ColumnarMenu, DefaultCompleter, DefaultHinter, Emacs, ExampleHighlighter, KeyCode,
     KeyModifiers, MenuBuilder, Prompt, PromptEditMode, PromptHistorySearch,
-    PromptHistorySearchStatus, Reedline, ReedlineEvent, ReedlineMenu, Signal,
-    default_emacs_keybindings,
+    PromptHistorySearchStatus, Reedline, ReedlineEvent, ReedlineMenu, Signal, ValidationResult,
+    Validator, default_emacs_keybindings,
 };
 use std::borrow::Cow;
+use std::env;
 use std::io::Result;
+use std::path::Path;
 
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
@@ -21,26 +24,26 @@ static DEFAULT_MULTILINE_INDICATOR: &str = "... ";
 struct ChouPrompt;
 
 impl Prompt for ChouPrompt {
-    fn render_prompt_left(&self) -> Cow<str> {
+    fn render_prompt_left(&self) -> Cow<'_, str> {
         Cow::Borrowed("")
     }
 
-    fn render_prompt_right(&self) -> Cow<str> {
+    fn render_prompt_right(&self) -> Cow<'_, str> {
         Cow::Borrowed("")
     }
 
-    fn render_prompt_indicator(&self, _edit_mode: PromptEditMode) -> Cow<str> {
+    fn render_prompt_indicator(&self, _edit_mode: PromptEditMode) -> Cow<'_, str> {
         Cow::Borrowed(DEFAULT_PROMPT_INDICATOR)
     }
 
-    fn render_prompt_multiline_indicator(&self) -> Cow<str> {
+    fn render_prompt_multiline_indicator(&self) -> Cow<'_, str> {
         Cow::Borrowed(DEFAULT_MULTILINE_INDICATOR)
     }
 
     fn render_prompt_history_search_indicator(
         &self,
         history_search: PromptHistorySearch,
-    ) -> Cow<str> {
+    ) -> Cow<'_, str> {
         let prefix = match history_search.status {
             PromptHistorySearchStatus::Passing => "",
             PromptHistorySearchStatus::Failing => "failing ",
@@ -53,6 +56,76 @@ impl Prompt for ChouPrompt {
     }
 }
 
+/// Keeps reading lines while the input so far can’t be complete, i.e. while brackets, strings or
+/// block comments are left open or the last line ends with an operator. Otherwise, line breaks
+/// end statements, so the input is evaluated as soon as a line is entered.
+#[derive(Clone, Copy, Debug)]
+struct ChouValidator;
+
+impl Validator for ChouValidator {
+    fn validate(&self, line: &str) -> ValidationResult {
+        let mut lexer = Lexer::new(line);
+        let mut depth = 0_isize;
+        let mut last_kind = None;
+
+        for token in lexer.by_ref() {
+            match token.kind {
+                TokenKind::LParen | TokenKind::LBrace | TokenKind::LBracket => depth += 1,
+                TokenKind::RParen | TokenKind::RBrace | TokenKind::RBracket => depth -= 1,
+                _ => {}
+            }
+
+            if !token.kind.is_trivia() {
+                last_kind = Some(token.kind);
+            }
+        }
+
+        let is_unterminated = lexer.into_errors().iter().any(|error| {
+            matches!(
+                error.kind,
+                LexErrorKind::UnterminatedString | LexErrorKind::UnterminatedBlockComment
+            )
+        });
+
+        let ends_with_operator = matches!(
+            last_kind,
+            Some(
+                TokenKind::Plus
+                    | TokenKind::Minus
+                    | TokenKind::Star
+                    | TokenKind::Slash
+                    | TokenKind::Equals
+                    | TokenKind::PlusEquals
+                    | TokenKind::MinusEquals
+                    | TokenKind::StarEquals
+                    | TokenKind::SlashEquals
+                    | TokenKind::EqualsEquals
+                    | TokenKind::BangEquals
+                    | TokenKind::Less
+                    | TokenKind::LessEquals
+                    | TokenKind::Greater
+                    | TokenKind::GreaterEquals
+                    | TokenKind::AndAnd
+                    | TokenKind::OrOr
+                    | TokenKind::Pipe
+                    | TokenKind::Bang
+                    | TokenKind::Comma
+                    | TokenKind::Dot
+                    | TokenKind::Colon
+                    | TokenKind::ColonColon
+                    | TokenKind::Arrow
+                    | TokenKind::FatArrow
+            )
+        );
+
+        if depth > 0 || is_unterminated || ends_with_operator {
+            ValidationResult::Incomplete
+        } else {
+            ValidationResult::Complete
+        }
+    }
+}
+
 struct ChouRepl {
     editor: Reedline,
     prompt: ChouPrompt,
@@ -66,7 +139,13 @@ impl ChouRepl {
     }
 
     fn build_editor() -> Reedline {
-        let keywords = vec!["fn".to_string(), "let".to_string()];
+        let keywords: Vec<_> = [
+            "break", "continue", "else", "enum", "false", "fn", "if", "let", "loop", "match",
+            "mod", "struct", "true", "use", "while",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
         let hinter = Box::new(DefaultHinter::default());
         let completer = Box::new(DefaultCompleter::new_with_wordlen(keywords.clone(), 2));
         let highlighter = Box::new(ExampleHighlighter::new(keywords.clone()));
@@ -89,6 +168,7 @@ impl ChouRepl {
             .with_completer(completer)
             .with_quick_completions(true)
             .with_highlighter(highlighter)
+            .with_validator(Box::new(ChouValidator))
             .with_edit_mode(edit_mode)
             .with_menu(ReedlineMenu::EngineCompleter(completion_menu))
     }
@@ -112,7 +192,12 @@ impl ChouRepl {
             .collect();
 
         dbg!(var_defs);
-        dbg!(hir::lower(&root));
+        let (database, stmts) = hir::lower(&root);
+        for error in database.errors() {
+            println!("{error}");
+        }
+
+        dbg!(database, stmts);
     }
 
     fn run(&mut self) -> Result<()> {
@@ -129,6 +214,127 @@ impl ChouRepl {
     }
 }
 
+/// Loads the program starting at `file` along with all of its modules, printing every problem
+/// found in them.
+fn check_program(file: &Path) -> Result<()> {
+    let tree = hir::ModuleTree::load(file)?;
+
+    for error in tree.errors() {
+        println!("{error}");
+    }
+
+    for (_, module) in tree.modules() {
+        let file = module.file.display();
+
+        for error in module.parse.errors() {
+            println!("{file}: {error}");
+        }
+
+        for error in ast::validation::validate(&module.parse.syntax()) {
+            println!("{file}: {error}");
+        }
+
+        let (database, _) = hir::lower(&module.root());
+        for error in database.errors() {
+            println!("{file}: {error}");
+        }
+    }
+
+    Ok(())
+}
+
 fn main() -> Result<()> {
-    ChouRepl::new().run()
+    match env::args_os().nth(1) {
+        Some(file) => check_program(Path::new(&file)),
+        None => ChouRepl::new().run(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_complete(input: &str) {
+        assert!(
+            matches!(ChouValidator.validate(input), ValidationResult::Complete),
+            "expected {input:?} to be complete",
+        );
+    }
+
+    fn check_incomplete(input: &str) {
+        assert!(
+            matches!(ChouValidator.validate(input), ValidationResult::Incomplete),
+            "expected {input:?} to be incomplete",
+        );
+    }
+
+    #[test]
+    fn complete_statements() {
+        check_complete("let a = 1");
+        check_complete("let a = 1\nlet b = 2");
+        check_complete("a + b");
+        check_complete("");
+    }
+
+    #[test]
+    fn incomplete_with_open_brackets() {
+        check_incomplete("f(a,");
+        check_incomplete("fn f() {");
+        check_incomplete("let xs = [1, 2");
+        check_incomplete("if a {\n  b\n} else {");
+    }
+
+    #[test]
+    fn complete_once_brackets_are_closed() {
+        check_complete("f(a,\n  b)");
+        check_complete("fn f() {\n  1\n}");
+        check_complete("let xs = [\n  1,\n  2,\n]");
+    }
+
+    #[test]
+    fn complete_with_unmatched_closing_bracket() {
+        check_complete("a)");
+    }
+
+    #[test]
+    fn incomplete_with_unterminated_string() {
+        check_incomplete("let s = \"abc");
+        check_complete("let s = \"abc\ndef\"");
+    }
+
+    #[test]
+    fn incomplete_with_unterminated_block_comment() {
+        check_incomplete("#[ abc");
+        check_incomplete("#[ a #[ b ]#");
+        check_complete("#[ a #[ b ]# ]# 1");
+    }
+
+    #[test]
+    fn ignore_brackets_in_strings_and_comments() {
+        check_complete("let s = \"(\"");
+        check_complete("1 # {");
+        check_complete("#[ ( ]# 1");
+    }
+
+    #[test]
+    fn incomplete_with_trailing_operator() {
+        check_incomplete("a +");
+        check_incomplete("let a =");
+        check_incomplete("a &&");
+        check_incomplete("a.");
+        check_incomplete("shapes::");
+        check_incomplete("let f = |x|");
+        check_incomplete("fn f() ->");
+    }
+
+    #[test]
+    fn incomplete_with_trailing_operator_before_comment() {
+        check_incomplete("a + # more to come");
+    }
+
+    #[test]
+    fn complete_after_operator_is_continued() {
+        check_complete("a +\n  b");
+        check_complete("let a =\n  1");
+    }
 }
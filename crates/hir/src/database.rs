@@ -1,17 +1,58 @@
-use crate::{BinaryOp, Expr, Stmt, UnaryOp};
-use la_arena::Arena;
-use syntax::SyntaxKind;
+mod captures;
+
+use crate::{
+    BinaryOp, Expr, ExprIdx, FieldDef, Float, Literal, LowerError, LowerErrorKind, MatchArm, Param,
+    Pat, PatIdx, Path, RecordField, Stmt, TypeRef, UnaryOp, Variant,
+};
+use la_arena::{Arena, ArenaMap};
+use smol_str::SmolStr;
+use std::collections::HashSet;
+use syntax::{SyntaxKind, SyntaxToken};
+use unicode_normalization::{UnicodeNormalization, is_nfc};
 
 #[derive(Debug, PartialEq, Eq, Default)]
 pub struct Database {
     exprs: Arena<Expr>,
+    pats: Arena<Pat>,
+    stmts: Arena<Stmt>,
+    /// maps the body of each closure to the variables it captures
+    captures: ArenaMap<ExprIdx, Vec<SmolStr>>,
+    errors: Vec<LowerError>,
 }
 
 impl Database {
+    pub fn errors(&self) -> &[LowerError] {
+        &self.errors
+    }
+
+    /// Returns the variables a closure captures, i.e. every name it refers to that isn’t bound
+    /// inside of it, in the order they’re first referred to. Returns `None` if `closure` isn’t a
+    /// closure.
+    pub fn captures(&self, closure: &Expr) -> Option<&[SmolStr]> {
+        match closure {
+            Expr::Closure { body, .. } => self.captures.get(*body).map(Vec::as_slice),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn compute_captures(&mut self, stmts: &[Stmt]) {
+        self.captures = captures::collect(self, stmts);
+    }
+
     pub(crate) fn lower_stmt(&mut self, ast: ast::Stmt) -> Option<Stmt> {
         let result = match ast {
+            ast::Stmt::EnumDef(ast) => self.lower_enum_def(&ast)?,
+            ast::Stmt::FnDef(ast) => self.lower_fn_def(&ast)?,
+            ast::Stmt::ModDef(ast) => Stmt::ModDef {
+                name: lower_name(&ast.name()?),
+            },
+            ast::Stmt::StructDef(ast) => self.lower_struct_def(&ast)?,
+            ast::Stmt::UseDef(ast) => Stmt::UseDef {
+                path: lower_path(&ast.path()?),
+            },
             ast::Stmt::VariableDef(ast) => Stmt::VariableDef {
-                name: ast.name()?.text().into(),
+                name: lower_name(&ast.name()?),
+                ty: ast.ty().and_then(|ty| lower_type_ref(&ty)),
                 value: self.lower_expr(ast.value()),
             },
             ast::Stmt::Expr(ast) => Stmt::Expr(self.lower_expr(Some(ast))),
@@ -20,22 +61,162 @@ impl Database {
         Some(result)
     }
 
+    fn lower_enum_def(&mut self, ast: &ast::EnumDef) -> Option<Stmt> {
+        let name = lower_name(&ast.name()?);
+
+        let mut variants = Vec::new();
+        let mut seen = HashSet::new();
+
+        for variant in ast
+            .variant_list()
+            .iter()
+            .flat_map(ast::VariantList::variants)
+        {
+            let variant_name = variant.name().unwrap();
+            let name = lower_name(&variant_name);
+
+            if !seen.insert(name.clone()) {
+                self.errors.push(LowerError {
+                    kind: LowerErrorKind::VariantAlreadyDeclared { name },
+                    range: variant_name.text_range(),
+                });
+                continue;
+            }
+
+            variants.push(Variant {
+                name,
+                fields: variant
+                    .param_list()
+                    .map(|param_list| lower_params(&param_list))
+                    .unwrap_or_default(),
+            });
+        }
+
+        Some(Stmt::EnumDef { name, variants })
+    }
+
+    fn lower_fn_def(&mut self, ast: &ast::FnDef) -> Option<Stmt> {
+        let name = lower_name(&ast.name()?);
+
+        let params = ast
+            .param_list()
+            .map(|param_list| lower_params(&param_list))
+            .unwrap_or_default();
+
+        let ret_ty = ast.ret_ty().and_then(|ty| lower_type_ref(&ty));
+
+        let body = self.lower_expr(ast.body().map(ast::Expr::BlockExpr));
+
+        Some(Stmt::FnDef {
+            name,
+            params,
+            ret_ty,
+            body,
+        })
+    }
+
+    fn lower_struct_def(&mut self, ast: &ast::StructDef) -> Option<Stmt> {
+        let name = lower_name(&ast.name()?);
+
+        let mut fields = Vec::new();
+        let mut seen = HashSet::new();
+
+        for field in ast.field_list().iter().flat_map(ast::FieldDefList::fields) {
+            let Some(field_name) = field.name() else {
+                continue;
+            };
+
+            let name = lower_name(&field_name);
+
+            if !seen.insert(name.clone()) {
+                self.errors.push(LowerError {
+                    kind: LowerErrorKind::FieldAlreadyDeclared { name },
+                    range: field_name.text_range(),
+                });
+                continue;
+            }
+
+            fields.push(FieldDef {
+                name,
+                ty: field.ty().and_then(|ty| lower_type_ref(&ty)),
+            });
+        }
+
+        Some(Stmt::StructDef { name, fields })
+    }
+
     pub(crate) fn lower_expr(&mut self, ast: Option<ast::Expr>) -> Expr {
         ast.map_or(Expr::Missing, |ast| match ast {
+            ast::Expr::ArrayExpr(ast) => self.lower_array(&ast),
+            ast::Expr::AssignExpr(ast) => self.lower_assign(&ast),
             ast::Expr::BinaryExpr(ast) => self.lower_binary(&ast),
-            ast::Expr::Literal(ast) => Expr::Literal { n: ast.parse() },
+            ast::Expr::BlockExpr(ast) => self.lower_block(&ast),
+            ast::Expr::BreakExpr(ast) => self.lower_break(&ast),
+            ast::Expr::CallExpr(ast) => self.lower_call(&ast),
+            ast::Expr::ClosureExpr(ast) => self.lower_closure(&ast),
+            ast::Expr::ContinueExpr(_) => Expr::Continue,
+            ast::Expr::FieldExpr(ast) => self.lower_field(&ast),
+            ast::Expr::IfExpr(ast) => self.lower_if(&ast),
+            ast::Expr::IndexExpr(ast) => self.lower_index(&ast),
+            ast::Expr::Literal(ast) => Expr::Literal(self.lower_literal(&ast)),
+            ast::Expr::LoopExpr(ast) => self.lower_loop(&ast),
+            ast::Expr::MatchExpr(ast) => self.lower_match(&ast),
             ast::Expr::ParenExpr(ast) => self.lower_expr(ast.expr()),
+            ast::Expr::PathExpr(ast) => Expr::Path(lower_path(&ast.path().unwrap())),
+            ast::Expr::RecordExpr(ast) => self.lower_record(&ast),
+            ast::Expr::TupleExpr(ast) => self.lower_tuple(&ast),
             ast::Expr::UnaryExpr(ast) => self.lower_unary(&ast),
             ast::Expr::VariableRef(ast) => self.lower_variable_ref(&ast),
+            ast::Expr::WhileExpr(ast) => self.lower_while(&ast),
         })
     }
 
+    fn lower_array(&mut self, ast: &ast::ArrayExpr) -> Expr {
+        let elements = ast
+            .elements()
+            .map(|element| {
+                let element = self.lower_expr(Some(element));
+                self.exprs.alloc(element)
+            })
+            .collect();
+
+        Expr::Array { elements }
+    }
+
+    fn lower_assign(&mut self, ast: &ast::AssignExpr) -> Expr {
+        let op = match ast.op().unwrap().kind() {
+            SyntaxKind::Equals => None,
+            SyntaxKind::PlusEquals => Some(BinaryOp::Add),
+            SyntaxKind::MinusEquals => Some(BinaryOp::Sub),
+            SyntaxKind::StarEquals => Some(BinaryOp::Mul),
+            SyntaxKind::SlashEquals => Some(BinaryOp::Div),
+            _ => unreachable!(),
+        };
+
+        let target = self.lower_expr(ast.target());
+        let value = self.lower_expr(ast.value());
+
+        Expr::Assign {
+            op,
+            target: self.exprs.alloc(target),
+            value: self.exprs.alloc(value),
+        }
+    }
+
     fn lower_binary(&mut self, ast: &ast::BinaryExpr) -> Expr {
         let op = match ast.op().unwrap().kind() {
             SyntaxKind::Plus => BinaryOp::Add,
             SyntaxKind::Minus => BinaryOp::Sub,
             SyntaxKind::Star => BinaryOp::Mul,
             SyntaxKind::Slash => BinaryOp::Div,
+            SyntaxKind::EqualsEquals => BinaryOp::Eq,
+            SyntaxKind::BangEquals => BinaryOp::NotEq,
+            SyntaxKind::Less => BinaryOp::Lt,
+            SyntaxKind::LessEquals => BinaryOp::LtEq,
+            SyntaxKind::Greater => BinaryOp::Gt,
+            SyntaxKind::GreaterEquals => BinaryOp::GtEq,
+            SyntaxKind::AndAnd => BinaryOp::And,
+            SyntaxKind::OrOr => BinaryOp::Or,
             _ => unreachable!(),
         };
 
@@ -49,9 +230,234 @@ impl Database {
         }
     }
 
+    fn lower_block(&mut self, ast: &ast::BlockExpr) -> Expr {
+        let stmts = ast
+            .stmts()
+            .filter_map(|stmt| {
+                let stmt = self.lower_stmt(stmt)?;
+                Some(self.stmts.alloc(stmt))
+            })
+            .collect();
+
+        let tail = ast.tail_expr().map(|tail| {
+            let tail = self.lower_expr(Some(tail));
+            self.exprs.alloc(tail)
+        });
+
+        Expr::Block { stmts, tail }
+    }
+
+    fn lower_break(&mut self, ast: &ast::BreakExpr) -> Expr {
+        let value = ast.value().map(|value| {
+            let value = self.lower_expr(Some(value));
+            self.exprs.alloc(value)
+        });
+
+        Expr::Break { value }
+    }
+
+    fn lower_call(&mut self, ast: &ast::CallExpr) -> Expr {
+        let callee = self.lower_expr(ast.callee());
+        let callee = self.exprs.alloc(callee);
+
+        let args = ast
+            .arg_list()
+            .map(|arg_list| {
+                arg_list
+                    .args()
+                    .map(|arg| {
+                        let arg = self.lower_expr(Some(arg));
+                        self.exprs.alloc(arg)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Expr::Call { callee, args }
+    }
+
+    fn lower_closure(&mut self, ast: &ast::ClosureExpr) -> Expr {
+        let params = ast
+            .param_list()
+            .map(|param_list| lower_params(&param_list))
+            .unwrap_or_default();
+
+        let body = self.lower_expr(ast.body());
+
+        Expr::Closure {
+            params,
+            body: self.exprs.alloc(body),
+        }
+    }
+
+    fn lower_field(&mut self, ast: &ast::FieldExpr) -> Expr {
+        let base = self.lower_expr(ast.base());
+
+        Expr::Field {
+            base: self.exprs.alloc(base),
+            // A missing field name has already been reported by the parser.
+            field: ast.field().map(|field| lower_name(&field)),
+        }
+    }
+
+    fn lower_if(&mut self, ast: &ast::IfExpr) -> Expr {
+        let condition = self.lower_expr(ast.condition());
+        let condition = self.exprs.alloc(condition);
+
+        let then_branch = self.lower_expr(ast.then_branch().map(ast::Expr::BlockExpr));
+        let then_branch = self.exprs.alloc(then_branch);
+
+        let else_branch = ast.else_kw().map(|_| {
+            let else_branch = self.lower_expr(ast.else_branch());
+            self.exprs.alloc(else_branch)
+        });
+
+        Expr::If {
+            condition,
+            then_branch,
+            else_branch,
+        }
+    }
+
+    fn lower_index(&mut self, ast: &ast::IndexExpr) -> Expr {
+        let base = self.lower_expr(ast.base());
+        let index = self.lower_expr(ast.index());
+
+        Expr::Index {
+            base: self.exprs.alloc(base),
+            index: self.exprs.alloc(index),
+        }
+    }
+
+    fn lower_literal(&self, ast: &ast::Literal) -> Literal {
+        match ast.kind() {
+            ast::LiteralKind::Number => Literal::Int(ast.parse()),
+            ast::LiteralKind::Float => Literal::Float(ast.parse_float().map(Float::from)),
+            ast::LiteralKind::String => Literal::String(ast.unescape()),
+            ast::LiteralKind::Bool => Literal::Bool(ast.as_bool().unwrap()),
+        }
+    }
+
+    fn lower_loop(&mut self, ast: &ast::LoopExpr) -> Expr {
+        let body = self.lower_expr(ast.body().map(ast::Expr::BlockExpr));
+
+        Expr::Loop {
+            body: self.exprs.alloc(body),
+        }
+    }
+
+    fn lower_match(&mut self, ast: &ast::MatchExpr) -> Expr {
+        let scrutinee = self.lower_expr(ast.scrutinee());
+        let scrutinee = self.exprs.alloc(scrutinee);
+
+        let arms = ast
+            .arm_list()
+            .iter()
+            .flat_map(ast::MatchArmList::arms)
+            .map(|arm| {
+                let pat = self.lower_pat(arm.pat());
+
+                let guard = arm.guard().map(|guard| {
+                    let condition = self.lower_expr(guard.condition());
+                    self.exprs.alloc(condition)
+                });
+
+                let body = self.lower_expr(arm.body());
+
+                MatchArm {
+                    pat: self.pats.alloc(pat),
+                    guard,
+                    body: self.exprs.alloc(body),
+                }
+            })
+            .collect();
+
+        Expr::Match { scrutinee, arms }
+    }
+
+    fn lower_record(&mut self, ast: &ast::RecordExpr) -> Expr {
+        let name = lower_name(&ast.name().unwrap());
+
+        let mut fields = Vec::new();
+        let mut seen = HashSet::new();
+
+        for field in ast
+            .field_list()
+            .iter()
+            .flat_map(ast::RecordFieldList::fields)
+        {
+            let field_name = field.name().unwrap();
+            let name = lower_name(&field_name);
+
+            if !seen.insert(name.clone()) {
+                self.errors.push(LowerError {
+                    kind: LowerErrorKind::FieldSpecifiedTwice { name },
+                    range: field_name.text_range(),
+                });
+                continue;
+            }
+
+            let value = self.lower_expr(field.value());
+
+            fields.push(RecordField {
+                name,
+                value: self.exprs.alloc(value),
+            });
+        }
+
+        Expr::Record { name, fields }
+    }
+
+    fn lower_pat(&mut self, ast: Option<ast::Pat>) -> Pat {
+        ast.map_or(Pat::Missing, |ast| match ast {
+            ast::Pat::IdentPat(ast) => {
+                let name = lower_name(&ast.name().unwrap());
+
+                if name == "_" {
+                    Pat::Wildcard
+                } else {
+                    Pat::Binding { name }
+                }
+            }
+            ast::Pat::LiteralPat(ast) => ast.literal().map_or(Pat::Missing, |literal| {
+                Pat::Literal(self.lower_literal(&literal))
+            }),
+            ast::Pat::ParenPat(ast) => self.lower_pat(ast.pat()),
+            ast::Pat::TuplePat(ast) => Pat::Tuple {
+                fields: self.lower_pat_fields(ast.fields()),
+            },
+            ast::Pat::VariantPat(ast) => Pat::Variant {
+                name: lower_name(&ast.name().unwrap()),
+                fields: self.lower_pat_fields(ast.fields()),
+            },
+        })
+    }
+
+    fn lower_pat_fields(&mut self, fields: impl Iterator<Item = ast::Pat>) -> Vec<PatIdx> {
+        fields
+            .map(|field| {
+                let field = self.lower_pat(Some(field));
+                self.pats.alloc(field)
+            })
+            .collect()
+    }
+
+    fn lower_tuple(&mut self, ast: &ast::TupleExpr) -> Expr {
+        let fields = ast
+            .fields()
+            .map(|field| {
+                let field = self.lower_expr(Some(field));
+                self.exprs.alloc(field)
+            })
+            .collect();
+
+        Expr::Tuple { fields }
+    }
+
     fn lower_unary(&mut self, ast: &ast::UnaryExpr) -> Expr {
         let op = match ast.op().unwrap().kind() {
             SyntaxKind::Minus => UnaryOp::Neg,
+            SyntaxKind::Bang => UnaryOp::Not,
             _ => unreachable!(),
         };
 
@@ -65,25 +471,81 @@ impl Database {
 
     fn lower_variable_ref(&mut self, ast: &ast::VariableRef) -> Expr {
         Expr::VariableRef {
-            var: ast.name().unwrap().text().into(),
+            var: lower_name(&ast.name().unwrap()),
+        }
+    }
+
+    fn lower_while(&mut self, ast: &ast::WhileExpr) -> Expr {
+        let condition = self.lower_expr(ast.condition());
+        let condition = self.exprs.alloc(condition);
+
+        let body = self.lower_expr(ast.body().map(ast::Expr::BlockExpr));
+        let body = self.exprs.alloc(body);
+
+        Expr::While { condition, body }
+    }
+}
+
+fn lower_params(ast: &ast::ParamList) -> Vec<Param> {
+    ast.params()
+        .filter_map(|param| {
+            Some(Param {
+                name: lower_name(&param.name()?),
+                ty: param.ty().and_then(|ty| lower_type_ref(&ty)),
+            })
+        })
+        .collect()
+}
+
+fn lower_type_ref(ast: &ast::TypeRef) -> Option<TypeRef> {
+    let result = match ast.kind() {
+        ast::TypeRefKind::Named => TypeRef::Named(lower_name(&ast.name()?)),
+        ast::TypeRefKind::Path => TypeRef::Path(lower_path(&ast.path()?)),
+        ast::TypeRefKind::Tuple => {
+            TypeRef::Tuple(ast.fields().filter_map(|ty| lower_type_ref(&ty)).collect())
         }
+        ast::TypeRefKind::Paren => return lower_type_ref(&ast.fields().next()?),
+    };
+
+    Some(result)
+}
+
+fn lower_path(ast: &ast::Path) -> Path {
+    Path {
+        segments: ast.segments().map(|segment| lower_name(&segment)).collect(),
+    }
+}
+
+/// Normalizes a name to NFC, so that equivalent spellings (e.g. `é` as a single code point, or as
+/// an `e` followed by a combining accent) refer to the same binding.
+pub(crate) fn lower_name(name: &SyntaxToken) -> SmolStr {
+    let text = name.text();
+
+    if is_nfc(text) {
+        text.into()
+    } else {
+        text.nfc().collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use text_size::TextRange;
 
     fn parse(input: &str) -> ast::Root {
         ast::Root::cast(parser::parse(input).syntax()).unwrap()
     }
 
-    fn check_stmt(input: &str, expected_hir: Stmt) {
+    fn check_stmt(input: &str, expected_hir: Stmt, expected_database: Database) {
         let root = parse(input);
         let ast = root.stmts().next().unwrap();
-        let hir = Database::default().lower_stmt(ast).unwrap();
+
+        let mut database = Database::default();
+        let hir = database.lower_stmt(ast).unwrap();
 
         assert_eq!(hir, expected_hir);
+        assert_eq!(database, expected_database);
     }
 
     fn check_expr(input: &str, expected_hir: Expr, expected_database: Database) {
@@ -107,11 +569,112 @@ mod tests {
             "let foo = bar",
             Stmt::VariableDef {
                 name: "foo".into(),
+                ty: None,
                 value: Expr::VariableRef { var: "bar".into() },
             },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_variable_def_with_type() {
+        check_stmt(
+            "let count: Int = 0",
+            Stmt::VariableDef {
+                name: "count".into(),
+                ty: Some(TypeRef::Named("Int".into())),
+                value: Expr::Literal(Literal::Int(Some(0))),
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_variable_def_with_path_type() {
+        check_stmt(
+            "let s: shapes::Shape = x",
+            Stmt::VariableDef {
+                name: "s".into(),
+                ty: Some(TypeRef::Path(Path {
+                    segments: vec!["shapes".into(), "Shape".into()],
+                })),
+                value: Expr::VariableRef { var: "x".into() },
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_variable_def_with_tuple_type() {
+        check_stmt(
+            "let a: ((), ((Int), Bool), (Int,)) = b",
+            Stmt::VariableDef {
+                name: "a".into(),
+                ty: Some(TypeRef::Tuple(vec![
+                    TypeRef::Tuple(Vec::new()),
+                    TypeRef::Tuple(vec![
+                        TypeRef::Named("Int".into()),
+                        TypeRef::Named("Bool".into()),
+                    ]),
+                    TypeRef::Tuple(vec![TypeRef::Named("Int".into())]),
+                ])),
+                value: Expr::VariableRef { var: "b".into() },
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_variable_def_with_missing_type() {
+        check_stmt(
+            "let a: = 1",
+            Stmt::VariableDef {
+                name: "a".into(),
+                ty: None,
+                value: Expr::Literal(Literal::Int(Some(1))),
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_mod_def() {
+        check_stmt(
+            "mod shapes",
+            Stmt::ModDef {
+                name: "shapes".into(),
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_mod_def_without_name() {
+        let root = parse("mod");
+        let ast = root.stmts().next().unwrap();
+        assert!(Database::default().lower_stmt(ast).is_none());
+    }
+
+    #[test]
+    fn lower_use_def() {
+        check_stmt(
+            "use shapes::area",
+            Stmt::UseDef {
+                path: Path {
+                    segments: vec!["shapes".into(), "area".into()],
+                },
+            },
+            Database::default(),
         );
     }
 
+    #[test]
+    fn lower_use_def_without_path() {
+        let root = parse("use");
+        let ast = root.stmts().next().unwrap();
+        assert!(Database::default().lower_stmt(ast).is_none());
+    }
+
     #[test]
     fn lower_variable_def_without_name() {
         let root = parse("let = 10");
@@ -125,100 +688,1273 @@ mod tests {
             "let a =",
             Stmt::VariableDef {
                 name: "a".into(),
+                ty: None,
                 value: Expr::Missing,
             },
+            Database::default(),
         );
     }
 
     #[test]
-    fn lower_expr_stmt() {
-        check_stmt("123", Stmt::Expr(Expr::Literal { n: Some(123) }));
+    fn lower_fn_def() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::VariableRef { var: "x".into() });
+
+        check_stmt(
+            "fn id(x) { x }",
+            Stmt::FnDef {
+                name: "id".into(),
+                params: vec![Param {
+                    name: "x".into(),
+                    ty: None,
+                }],
+                ret_ty: None,
+                body: Expr::Block {
+                    stmts: Vec::new(),
+                    tail: Some(x),
+                },
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
     }
 
     #[test]
-    fn lower_binary_expr() {
+    fn lower_fn_def_with_types() {
         let mut exprs = Arena::new();
-        let lhs = exprs.alloc(Expr::Literal { n: Some(1) });
-        let rhs = exprs.alloc(Expr::Literal { n: Some(2) });
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
 
-        check_expr(
-            "1 + 2",
-            Expr::Binary {
-                op: BinaryOp::Add,
-                lhs,
-                rhs,
+        check_stmt(
+            "fn first(a: Int, b) -> Int { a }",
+            Stmt::FnDef {
+                name: "first".into(),
+                params: vec![
+                    Param {
+                        name: "a".into(),
+                        ty: Some(TypeRef::Named("Int".into())),
+                    },
+                    Param {
+                        name: "b".into(),
+                        ty: None,
+                    },
+                ],
+                ret_ty: Some(TypeRef::Named("Int".into())),
+                body: Expr::Block {
+                    stmts: Vec::new(),
+                    tail: Some(a),
+                },
+            },
+            Database {
+                exprs,
+                ..Database::default()
             },
-            Database { exprs },
         );
     }
 
     #[test]
-    fn lower_binary_expr_without_rhs() {
+    fn lower_fn_def_with_multiple_params_and_statements() {
         let mut exprs = Arena::new();
-        let lhs = exprs.alloc(Expr::Literal { n: Some(10) });
-        let rhs = exprs.alloc(Expr::Missing);
+        let b = exprs.alloc(Expr::VariableRef { var: "b".into() });
 
-        check_expr(
-            "10 -",
-            Expr::Binary {
-                op: BinaryOp::Sub,
-                lhs,
-                rhs,
+        let mut stmts = Arena::new();
+        let c = stmts.alloc(Stmt::VariableDef {
+            name: "c".into(),
+            ty: None,
+            value: Expr::VariableRef { var: "a".into() },
+        });
+
+        check_stmt(
+            "fn f(a, b) {\n  let c = a\n  b\n}",
+            Stmt::FnDef {
+                name: "f".into(),
+                params: vec![
+                    Param {
+                        name: "a".into(),
+                        ty: None,
+                    },
+                    Param {
+                        name: "b".into(),
+                        ty: None,
+                    },
+                ],
+                ret_ty: None,
+                body: Expr::Block {
+                    stmts: vec![c],
+                    tail: Some(b),
+                },
+            },
+            Database {
+                exprs,
+                stmts,
+                ..Database::default()
             },
-            Database { exprs },
         );
     }
 
     #[test]
-    fn lower_literal() {
-        check_expr("999", Expr::Literal { n: Some(999) }, Database::default());
+    fn lower_fn_def_without_name() {
+        let root = parse("fn {}");
+        let ast = root.stmts().next().unwrap();
+        assert!(Database::default().lower_stmt(ast).is_none());
     }
 
     #[test]
-    fn lower_paren_expr() {
-        check_expr(
-            "((((((abc))))))",
-            Expr::VariableRef { var: "abc".into() },
+    fn lower_fn_def_without_body() {
+        check_stmt(
+            "fn f(a)",
+            Stmt::FnDef {
+                name: "f".into(),
+                params: vec![Param {
+                    name: "a".into(),
+                    ty: None,
+                }],
+                ret_ty: None,
+                body: Expr::Missing,
+            },
             Database::default(),
         );
     }
 
     #[test]
-    fn lower_unary_expr() {
-        let mut exprs = Arena::new();
-        let ten = exprs.alloc(Expr::Literal { n: Some(10) });
-
-        check_expr(
-            "-10",
-            Expr::Unary {
-                op: UnaryOp::Neg,
-                expr: ten,
+    fn lower_enum_def() {
+        check_stmt(
+            "enum Shape { Circle(r: Float), Rect(w, h), Empty }",
+            Stmt::EnumDef {
+                name: "Shape".into(),
+                variants: vec![
+                    Variant {
+                        name: "Circle".into(),
+                        fields: vec![Param {
+                            name: "r".into(),
+                            ty: Some(TypeRef::Named("Float".into())),
+                        }],
+                    },
+                    Variant {
+                        name: "Rect".into(),
+                        fields: vec![
+                            Param {
+                                name: "w".into(),
+                                ty: None,
+                            },
+                            Param {
+                                name: "h".into(),
+                                ty: None,
+                            },
+                        ],
+                    },
+                    Variant {
+                        name: "Empty".into(),
+                        fields: Vec::new(),
+                    },
+                ],
             },
-            Database { exprs },
+            Database::default(),
         );
     }
 
     #[test]
-    fn lower_unary_expr_without_expr() {
-        let mut exprs = Arena::new();
-        let expr = exprs.alloc(Expr::Missing);
-
-        check_expr(
-            "-",
-            Expr::Unary {
-                op: UnaryOp::Neg,
-                expr,
+    fn lower_enum_def_with_duplicate_variant() {
+        check_stmt(
+            "enum A { B, B(c) }",
+            Stmt::EnumDef {
+                name: "A".into(),
+                variants: vec![Variant {
+                    name: "B".into(),
+                    fields: Vec::new(),
+                }],
+            },
+            Database {
+                errors: vec![LowerError {
+                    kind: LowerErrorKind::VariantAlreadyDeclared { name: "B".into() },
+                    range: TextRange::new(12.into(), 13.into()),
+                }],
+                ..Database::default()
             },
-            Database { exprs },
         );
     }
 
     #[test]
-    fn lower_variable_ref() {
-        check_expr(
-            "foo",
-            Expr::VariableRef { var: "foo".into() },
+    fn lower_struct_def() {
+        check_stmt(
+            "struct Point { x: Int, y }",
+            Stmt::StructDef {
+                name: "Point".into(),
+                fields: vec![
+                    FieldDef {
+                        name: "x".into(),
+                        ty: Some(TypeRef::Named("Int".into())),
+                    },
+                    FieldDef {
+                        name: "y".into(),
+                        ty: None,
+                    },
+                ],
+            },
             Database::default(),
         );
     }
+
+    #[test]
+    fn lower_struct_def_with_duplicate_field() {
+        check_stmt(
+            "struct Point { x: Int, y: Int, x: Bool }",
+            Stmt::StructDef {
+                name: "Point".into(),
+                fields: vec![
+                    FieldDef {
+                        name: "x".into(),
+                        ty: Some(TypeRef::Named("Int".into())),
+                    },
+                    FieldDef {
+                        name: "y".into(),
+                        ty: Some(TypeRef::Named("Int".into())),
+                    },
+                ],
+            },
+            Database {
+                errors: vec![LowerError {
+                    kind: LowerErrorKind::FieldAlreadyDeclared { name: "x".into() },
+                    range: TextRange::new(31.into(), 32.into()),
+                }],
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_struct_def_without_name() {
+        let root = parse("struct { x: Int }");
+        let ast = root.stmts().next().unwrap();
+        assert!(Database::default().lower_stmt(ast).is_none());
+    }
+
+    #[test]
+    fn lower_expr_stmt() {
+        check_stmt(
+            "123",
+            Stmt::Expr(Expr::Literal(Literal::Int(Some(123)))),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_binary_expr() {
+        let mut exprs = Arena::new();
+        let lhs = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let rhs = exprs.alloc(Expr::Literal(Literal::Int(Some(2))));
+
+        check_expr(
+            "1 + 2",
+            Expr::Binary {
+                op: BinaryOp::Add,
+                lhs,
+                rhs,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_assign_expr() {
+        let mut exprs = Arena::new();
+        let target = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let value = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+
+        check_expr(
+            "a = 1",
+            Expr::Assign {
+                op: None,
+                target,
+                value,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_compound_assign_expr() {
+        let mut exprs = Arena::new();
+        let target = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let value = exprs.alloc(Expr::VariableRef { var: "b".into() });
+
+        check_expr(
+            "a /= b",
+            Expr::Assign {
+                op: Some(BinaryOp::Div),
+                target,
+                value,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_assign_expr_without_value() {
+        let mut exprs = Arena::new();
+        let target = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let value = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "a -=",
+            Expr::Assign {
+                op: Some(BinaryOp::Sub),
+                target,
+                value,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_binary_expr_without_rhs() {
+        let mut exprs = Arena::new();
+        let lhs = exprs.alloc(Expr::Literal(Literal::Int(Some(10))));
+        let rhs = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "10 -",
+            Expr::Binary {
+                op: BinaryOp::Sub,
+                lhs,
+                rhs,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_block_expr_without_tail() {
+        let mut stmts = Arena::new();
+        let a = stmts.alloc(Stmt::Expr(Expr::VariableRef { var: "a".into() }));
+
+        check_expr(
+            "{ a; }",
+            Expr::Block {
+                stmts: vec![a],
+                tail: None,
+            },
+            Database {
+                stmts,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_nested_block_expr() {
+        let mut exprs = Arena::new();
+        let mut stmts = Arena::new();
+        let inner = stmts.alloc(Stmt::VariableDef {
+            name: "x".into(),
+            ty: None,
+            value: Expr::Literal(Literal::Int(Some(1))),
+        });
+        let inner_block = exprs.alloc(Expr::Block {
+            stmts: vec![inner],
+            tail: None,
+        });
+        check_expr(
+            "{ { let x = 1; } }",
+            Expr::Block {
+                stmts: Vec::new(),
+                tail: Some(inner_block),
+            },
+            Database {
+                exprs,
+                stmts,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_array_expr() {
+        let mut exprs = Arena::new();
+        let one = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+
+        check_expr(
+            "[1, a]",
+            Expr::Array {
+                elements: vec![one, a],
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_closure_expr() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::VariableRef { var: "x".into() });
+        let y = exprs.alloc(Expr::VariableRef { var: "y".into() });
+        let body = exprs.alloc(Expr::Binary {
+            op: BinaryOp::Add,
+            lhs: x,
+            rhs: y,
+        });
+
+        check_expr(
+            "|x, y: Int| x + y",
+            Expr::Closure {
+                params: vec![
+                    Param {
+                        name: "x".into(),
+                        ty: None,
+                    },
+                    Param {
+                        name: "y".into(),
+                        ty: Some(TypeRef::Named("Int".into())),
+                    },
+                ],
+                body,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_closure_expr_without_params_or_body() {
+        let mut exprs = Arena::new();
+        let body = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "||",
+            Expr::Closure {
+                params: Vec::new(),
+                body,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_index_expr() {
+        let mut exprs = Arena::new();
+        let base = exprs.alloc(Expr::VariableRef { var: "xs".into() });
+        let index = exprs.alloc(Expr::Literal(Literal::Int(Some(0))));
+
+        check_expr(
+            "xs[0]",
+            Expr::Index { base, index },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_index_expr_without_index() {
+        let mut exprs = Arena::new();
+        let base = exprs.alloc(Expr::VariableRef { var: "xs".into() });
+        let index = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "xs[]",
+            Expr::Index { base, index },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_match_expr() {
+        let mut exprs = Arena::new();
+        let scrutinee = exprs.alloc(Expr::VariableRef { var: "s".into() });
+        let w = exprs.alloc(Expr::VariableRef { var: "w".into() });
+        let zero = exprs.alloc(Expr::Literal(Literal::Int(Some(0))));
+        let guard = exprs.alloc(Expr::Binary {
+            op: BinaryOp::Gt,
+            lhs: w,
+            rhs: zero,
+        });
+        let first_body = exprs.alloc(Expr::VariableRef { var: "w".into() });
+        let second_body = exprs.alloc(Expr::VariableRef { var: "x".into() });
+        let third_body = exprs.alloc(Expr::Literal(Literal::Int(Some(0))));
+
+        let mut pats = Arena::new();
+        let w_pat = pats.alloc(Pat::Binding { name: "w".into() });
+        let wildcard = pats.alloc(Pat::Wildcard);
+        let first_pat = pats.alloc(Pat::Variant {
+            name: "Rect".into(),
+            fields: vec![w_pat, wildcard],
+        });
+        let one = pats.alloc(Pat::Literal(Literal::Int(Some(1))));
+        let x_pat = pats.alloc(Pat::Binding { name: "x".into() });
+        let second_pat = pats.alloc(Pat::Tuple {
+            fields: vec![one, x_pat],
+        });
+        let third_pat = pats.alloc(Pat::Wildcard);
+
+        check_expr(
+            "match s {\n  Rect(w, _) if w > 0 => w\n  (1, (x)) => x\n  _ => 0\n}",
+            Expr::Match {
+                scrutinee,
+                arms: vec![
+                    MatchArm {
+                        pat: first_pat,
+                        guard: Some(guard),
+                        body: first_body,
+                    },
+                    MatchArm {
+                        pat: second_pat,
+                        guard: None,
+                        body: second_body,
+                    },
+                    MatchArm {
+                        pat: third_pat,
+                        guard: None,
+                        body: third_body,
+                    },
+                ],
+            },
+            Database {
+                exprs,
+                pats,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_match_arm_without_pattern_or_body() {
+        let mut exprs = Arena::new();
+        let scrutinee = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let body = exprs.alloc(Expr::Missing);
+
+        let mut pats = Arena::new();
+        let pat = pats.alloc(Pat::Missing);
+
+        check_expr(
+            "match a { => }",
+            Expr::Match {
+                scrutinee,
+                arms: vec![MatchArm {
+                    pat,
+                    guard: None,
+                    body,
+                }],
+            },
+            Database {
+                exprs,
+                pats,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_path_expr() {
+        check_expr(
+            "a::b::c",
+            Expr::Path(Path {
+                segments: vec!["a".into(), "b".into(), "c".into()],
+            }),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_record_expr() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let y = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "Point { x: 1, y: }",
+            Expr::Record {
+                name: "Point".into(),
+                fields: vec![
+                    RecordField {
+                        name: "x".into(),
+                        value: x,
+                    },
+                    RecordField {
+                        name: "y".into(),
+                        value: y,
+                    },
+                ],
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_record_expr_with_duplicate_field() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+
+        check_expr(
+            "Point { x: 1, x: 2 }",
+            Expr::Record {
+                name: "Point".into(),
+                fields: vec![RecordField {
+                    name: "x".into(),
+                    value: x,
+                }],
+            },
+            Database {
+                exprs,
+                errors: vec![LowerError {
+                    kind: LowerErrorKind::FieldSpecifiedTwice { name: "x".into() },
+                    range: TextRange::new(14.into(), 15.into()),
+                }],
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_field_expr() {
+        let mut exprs = Arena::new();
+        let p = exprs.alloc(Expr::VariableRef { var: "p".into() });
+        let base = exprs.alloc(Expr::Field {
+            base: p,
+            field: Some("a".into()),
+        });
+
+        check_expr(
+            "p.a.b",
+            Expr::Field {
+                base,
+                field: Some("b".into()),
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_field_expr_without_name() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let base = exprs.alloc(Expr::Record {
+            name: "Point".into(),
+            fields: vec![RecordField {
+                name: "x".into(),
+                value: x,
+            }],
+        });
+
+        check_expr(
+            "Point { x: 1, x: 2 }.",
+            Expr::Field { base, field: None },
+            Database {
+                exprs,
+                errors: vec![LowerError {
+                    kind: LowerErrorKind::FieldSpecifiedTwice { name: "x".into() },
+                    range: TextRange::new(14.into(), 15.into()),
+                }],
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_tuple_expr() {
+        let mut exprs = Arena::new();
+        let empty = exprs.alloc(Expr::Tuple { fields: Vec::new() });
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let single = exprs.alloc(Expr::Tuple { fields: vec![a] });
+
+        check_expr(
+            "((), (a,))",
+            Expr::Tuple {
+                fields: vec![empty, single],
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_call() {
+        let mut exprs = Arena::new();
+        let callee = exprs.alloc(Expr::VariableRef { var: "f".into() });
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let one = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let two = exprs.alloc(Expr::Literal(Literal::Int(Some(2))));
+        let sum = exprs.alloc(Expr::Binary {
+            op: BinaryOp::Add,
+            lhs: one,
+            rhs: two,
+        });
+
+        check_expr(
+            "f(a, 1 + 2)",
+            Expr::Call {
+                callee,
+                args: vec![a, sum],
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_call_without_args() {
+        let mut exprs = Arena::new();
+        let callee = exprs.alloc(Expr::VariableRef { var: "f".into() });
+
+        check_expr(
+            "f()",
+            Expr::Call {
+                callee,
+                args: Vec::new(),
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_nested_call() {
+        let mut exprs = Arena::new();
+        let f = exprs.alloc(Expr::VariableRef { var: "f".into() });
+        let g = exprs.alloc(Expr::VariableRef { var: "g".into() });
+        let x = exprs.alloc(Expr::VariableRef { var: "x".into() });
+        let inner = exprs.alloc(Expr::Call {
+            callee: g,
+            args: vec![x],
+        });
+
+        check_expr(
+            "f(g(x))",
+            Expr::Call {
+                callee: f,
+                args: vec![inner],
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_if_expr() {
+        let mut exprs = Arena::new();
+        let one = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let two = exprs.alloc(Expr::Literal(Literal::Int(Some(2))));
+        let then_branch = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: Some(two),
+        });
+        let three = exprs.alloc(Expr::Literal(Literal::Int(Some(3))));
+        let else_branch = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: Some(three),
+        });
+
+        check_expr(
+            "if 1 { 2 } else { 3 }",
+            Expr::If {
+                condition: one,
+                then_branch,
+                else_branch: Some(else_branch),
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_if_expr_without_else() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let then_branch = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: None,
+        });
+
+        check_expr(
+            "if a {}",
+            Expr::If {
+                condition: a,
+                then_branch,
+                else_branch: None,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_else_if_chain() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let then_a = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: None,
+        });
+        let b = exprs.alloc(Expr::VariableRef { var: "b".into() });
+        let then_b = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: None,
+        });
+        let else_if = exprs.alloc(Expr::If {
+            condition: b,
+            then_branch: then_b,
+            else_branch: None,
+        });
+
+        check_expr(
+            "if a {} else if b {}",
+            Expr::If {
+                condition: a,
+                then_branch: then_a,
+                else_branch: Some(else_if),
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_if_expr_with_block_condition() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let condition = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: Some(a),
+        });
+        let then_branch = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: None,
+        });
+
+        check_expr(
+            "if { a } {}",
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch: None,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_if_expr_without_condition_or_branches() {
+        let mut exprs = Arena::new();
+        let condition = exprs.alloc(Expr::Missing);
+        let then_branch = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "if",
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch: None,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_if_expr_without_else_branch() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let then_branch = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: None,
+        });
+        let else_branch = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "if a {} else",
+            Expr::If {
+                condition: a,
+                then_branch,
+                else_branch: Some(else_branch),
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_while_expr() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let continue_expr = exprs.alloc(Expr::Continue);
+        let body = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: Some(continue_expr),
+        });
+
+        check_expr(
+            "while a { continue }",
+            Expr::While { condition: a, body },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_while_expr_without_body() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+        let body = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "while a",
+            Expr::While { condition: a, body },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_loop_expr_with_break_value() {
+        let mut exprs = Arena::new();
+        let one = exprs.alloc(Expr::Literal(Literal::Int(Some(1))));
+        let break_expr = exprs.alloc(Expr::Break { value: Some(one) });
+        let body = exprs.alloc(Expr::Block {
+            stmts: Vec::new(),
+            tail: Some(break_expr),
+        });
+
+        check_expr(
+            "loop { break 1 }",
+            Expr::Loop { body },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_break_expr_without_value() {
+        check_expr("break", Expr::Break { value: None }, Database::default());
+    }
+
+    #[test]
+    fn lower_literal() {
+        check_expr(
+            "999",
+            Expr::Literal(Literal::Int(Some(999))),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_literals_with_radix_and_separators() {
+        check_expr(
+            "0xFF_FF",
+            Expr::Literal(Literal::Int(Some(0xFFFF))),
+            Database::default(),
+        );
+        check_expr(
+            "0o17",
+            Expr::Literal(Literal::Int(Some(0o17))),
+            Database::default(),
+        );
+        check_expr(
+            "0b1_01",
+            Expr::Literal(Literal::Int(Some(0b101))),
+            Database::default(),
+        );
+        check_expr(
+            "1_000",
+            Expr::Literal(Literal::Int(Some(1000))),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_malformed_number_literals() {
+        check_expr(
+            "0b12",
+            Expr::Literal(Literal::Int(None)),
+            Database::default(),
+        );
+        check_expr("0x", Expr::Literal(Literal::Int(None)), Database::default());
+    }
+
+    #[test]
+    fn lower_float_literals() {
+        check_expr(
+            "2.5e3",
+            Expr::Literal(Literal::Float(Some(2500.0.into()))),
+            Database::default(),
+        );
+        check_expr(
+            "1_000.25",
+            Expr::Literal(Literal::Float(Some(1000.25.into()))),
+            Database::default(),
+        );
+        check_expr(
+            "1e-",
+            Expr::Literal(Literal::Float(None)),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_bool_literals() {
+        check_expr(
+            "true",
+            Expr::Literal(Literal::Bool(true)),
+            Database::default(),
+        );
+        check_expr(
+            "false",
+            Expr::Literal(Literal::Bool(false)),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_string_literal() {
+        check_expr(
+            r#""caf\u{e9}\n""#,
+            Expr::Literal(Literal::String(Some("café\n".to_string()))),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_string_literal_with_invalid_escape() {
+        check_expr(
+            r#""\q""#,
+            Expr::Literal(Literal::String(None)),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_unterminated_string_literal() {
+        check_expr(
+            r#""abc"#,
+            Expr::Literal(Literal::String(None)),
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_paren_expr() {
+        check_expr(
+            "((((((abc))))))",
+            Expr::VariableRef { var: "abc".into() },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_unary_expr() {
+        let mut exprs = Arena::new();
+        let ten = exprs.alloc(Expr::Literal(Literal::Int(Some(10))));
+
+        check_expr(
+            "-10",
+            Expr::Unary {
+                op: UnaryOp::Neg,
+                expr: ten,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_not_expr() {
+        let mut exprs = Arena::new();
+        let a = exprs.alloc(Expr::VariableRef { var: "a".into() });
+
+        check_expr(
+            "!a",
+            Expr::Unary {
+                op: UnaryOp::Not,
+                expr: a,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_unary_expr_without_expr() {
+        let mut exprs = Arena::new();
+        let expr = exprs.alloc(Expr::Missing);
+
+        check_expr(
+            "-",
+            Expr::Unary {
+                op: UnaryOp::Neg,
+                expr,
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    #[test]
+    fn lower_variable_ref() {
+        check_expr(
+            "foo",
+            Expr::VariableRef { var: "foo".into() },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_non_ascii_variable_ref() {
+        check_expr(
+            "naïve_日本語",
+            Expr::VariableRef {
+                var: "naïve_日本語".into(),
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_names_to_nfc() {
+        // The definition uses a decomposed ‘é’, while the reference uses a precomposed one.
+        check_stmt(
+            "let cafe\u{301} = caf\u{e9}",
+            Stmt::VariableDef {
+                name: "caf\u{e9}".into(),
+                ty: None,
+                value: Expr::VariableRef {
+                    var: "caf\u{e9}".into(),
+                },
+            },
+            Database::default(),
+        );
+    }
+
+    #[test]
+    fn lower_fn_def_names_to_nfc() {
+        let mut exprs = Arena::new();
+        let x = exprs.alloc(Expr::VariableRef {
+            var: "\u{c5}".into(),
+        });
+
+        check_stmt(
+            "fn A\u{30a}(A\u{30a}) { \u{c5} }",
+            Stmt::FnDef {
+                name: "\u{c5}".into(),
+                params: vec![Param {
+                    name: "\u{c5}".into(),
+                    ty: None,
+                }],
+                ret_ty: None,
+                body: Expr::Block {
+                    stmts: Vec::new(),
+                    tail: Some(x),
+                },
+            },
+            Database {
+                exprs,
+                ..Database::default()
+            },
+        );
+    }
+
+    // The parser limits how deeply the syntax tree can nest, so lowering mustn’t overflow the stack
+    // however deeply nested the input is.
+
+    #[test]
+    fn lower_deeply_nested_parens() {
+        let root = parse(&format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000)));
+        crate::lower(&root);
+    }
+
+    #[test]
+    fn lower_deeply_nested_unary_exprs() {
+        let root = parse(&format!("{}1", "-".repeat(100_000)));
+        crate::lower(&root);
+    }
+
+    #[test]
+    fn lower_long_binary_expr_chain() {
+        let root = parse(&format!("1{}", " * 2".repeat(100_000)));
+        crate::lower(&root);
+    }
+
+    #[test]
+    fn lower_deeply_nested_pats() {
+        let root = parse(&format!(
+            "match a {{ {}b{} => c }}",
+            "Some(".repeat(100_000),
+            ")".repeat(100_000),
+        ));
+        crate::lower(&root);
+    }
+
+    #[test]
+    fn lower_deeply_nested_blocks() {
+        let root = parse(&format!(
+            "{}{}",
+            "fn f() { if a {} else {".repeat(100_000),
+            "} }".repeat(100_000),
+        ));
+        crate::lower(&root);
+    }
 }
@@ -0,0 +1,285 @@
+use super::Database;
+use crate::{Expr, ExprIdx, Pat, PatIdx, Stmt};
+use la_arena::ArenaMap;
+use smol_str::SmolStr;
+
+pub(super) fn collect(db: &Database, stmts: &[Stmt]) -> ArenaMap<ExprIdx, Vec<SmolStr>> {
+    let mut collector = Collector {
+        db,
+        scopes: vec![Vec::new()],
+        closures: Vec::new(),
+        captures: ArenaMap::default(),
+    };
+
+    for stmt in stmts {
+        collector.stmt(stmt);
+    }
+
+    collector.captures
+}
+
+struct Collector<'a> {
+    db: &'a Database,
+    /// the names bound in each enclosing scope, innermost last
+    scopes: Vec<Vec<SmolStr>>,
+    /// the closures we’re inside of, innermost last, each with the number of scopes that were
+    /// outside of it and the names it has captured so far
+    closures: Vec<(usize, Vec<SmolStr>)>,
+    captures: ArenaMap<ExprIdx, Vec<SmolStr>>,
+}
+
+impl Collector<'_> {
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::FnDef {
+                name, params, body, ..
+            } => {
+                // The function is in scope inside of its own body, so that it can recurse.
+                self.bind(name.clone());
+
+                self.scopes
+                    .push(params.iter().map(|param| param.name.clone()).collect());
+                self.expr(body);
+                self.scopes.pop();
+            }
+            Stmt::VariableDef { name, value, .. } => {
+                // The variable isn’t in scope in its own value, so `let a = a` refers to an outer
+                // `a`.
+                self.expr(value);
+                self.bind(name.clone());
+            }
+            // The last segment of the path is the name it imports.
+            Stmt::UseDef { path } => self.bind(path.segments.last().unwrap().clone()),
+            Stmt::Expr(expr) => self.expr(expr),
+            Stmt::EnumDef { .. } | Stmt::ModDef { .. } | Stmt::StructDef { .. } => {}
+        }
+    }
+
+    fn expr_idx(&mut self, idx: ExprIdx) {
+        self.expr(&self.db.exprs[idx]);
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            // Paths refer to items in other modules, which are never captured.
+            Expr::Missing | Expr::Continue | Expr::Literal(_) | Expr::Path(_) => {}
+            Expr::Array { elements } => elements.iter().for_each(|&idx| self.expr_idx(idx)),
+            Expr::Assign { target, value, .. } => {
+                self.expr_idx(*target);
+                self.expr_idx(*value);
+            }
+            Expr::Binary { lhs, rhs, .. } => {
+                self.expr_idx(*lhs);
+                self.expr_idx(*rhs);
+            }
+            Expr::Block { stmts, tail } => {
+                self.scopes.push(Vec::new());
+
+                for &idx in stmts {
+                    self.stmt(&self.db.stmts[idx]);
+                }
+
+                if let Some(tail) = tail {
+                    self.expr_idx(*tail);
+                }
+
+                self.scopes.pop();
+            }
+            Expr::Break { value } => {
+                if let Some(value) = value {
+                    self.expr_idx(*value);
+                }
+            }
+            Expr::Call { callee, args } => {
+                self.expr_idx(*callee);
+                args.iter().for_each(|&idx| self.expr_idx(idx));
+            }
+            Expr::Closure { params, body } => {
+                self.closures.push((self.scopes.len(), Vec::new()));
+                self.scopes
+                    .push(params.iter().map(|param| param.name.clone()).collect());
+
+                self.expr_idx(*body);
+
+                self.scopes.pop();
+                let (_, captures) = self.closures.pop().unwrap();
+                self.captures.insert(*body, captures);
+            }
+            Expr::Field { base, .. } => self.expr_idx(*base),
+            Expr::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr_idx(*condition);
+                self.expr_idx(*then_branch);
+
+                if let Some(else_branch) = else_branch {
+                    self.expr_idx(*else_branch);
+                }
+            }
+            Expr::Index { base, index } => {
+                self.expr_idx(*base);
+                self.expr_idx(*index);
+            }
+            Expr::Loop { body } => self.expr_idx(*body),
+            Expr::Match { scrutinee, arms } => {
+                self.expr_idx(*scrutinee);
+
+                for arm in arms {
+                    let mut bindings = Vec::new();
+                    self.pat_bindings(arm.pat, &mut bindings);
+                    self.scopes.push(bindings);
+
+                    if let Some(guard) = arm.guard {
+                        self.expr_idx(guard);
+                    }
+
+                    self.expr_idx(arm.body);
+
+                    self.scopes.pop();
+                }
+            }
+            Expr::Record { fields, .. } => {
+                fields.iter().for_each(|field| self.expr_idx(field.value));
+            }
+            Expr::Tuple { fields } => fields.iter().for_each(|&idx| self.expr_idx(idx)),
+            Expr::Unary { expr, .. } => self.expr_idx(*expr),
+            Expr::VariableRef { var } => self.reference(var),
+            Expr::While { condition, body } => {
+                self.expr_idx(*condition);
+                self.expr_idx(*body);
+            }
+        }
+    }
+
+    fn pat_bindings(&self, idx: PatIdx, bindings: &mut Vec<SmolStr>) {
+        match &self.db.pats[idx] {
+            Pat::Missing | Pat::Wildcard | Pat::Literal(_) => {}
+            Pat::Binding { name } => bindings.push(name.clone()),
+            Pat::Tuple { fields } | Pat::Variant { fields, .. } => {
+                for &field in fields {
+                    self.pat_bindings(field, bindings);
+                }
+            }
+        }
+    }
+
+    fn bind(&mut self, name: SmolStr) {
+        self.scopes.last_mut().unwrap().push(name);
+    }
+
+    /// Records a reference to `name` as a capture of every enclosing closure that the name is
+    /// bound outside of, or of all of them if it isn’t bound anywhere.
+    fn reference(&mut self, name: &SmolStr) {
+        let binding_depth = self
+            .scopes
+            .iter()
+            .rposition(|scope| scope.contains(name))
+            .map_or(0, |idx| idx + 1);
+
+        for (depth, captures) in self.closures.iter_mut().rev() {
+            if *depth < binding_depth {
+                break;
+            }
+
+            if !captures.contains(name) {
+                captures.push(name.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Expr, Stmt};
+
+    /// Checks the captures of every closure in the order their bodies were lowered, so a closure
+    /// comes after the ones nested inside of it.
+    fn check(input: &str, expected: &[&[&str]]) {
+        let root = ast::Root::cast(parser::parse(input).syntax()).unwrap();
+        let (database, _) = crate::lower(&root);
+
+        let captures: Vec<Vec<&str>> = database
+            .captures
+            .values()
+            .map(|captures| captures.iter().map(|name| name.as_str()).collect())
+            .collect();
+
+        assert_eq!(captures, expected);
+    }
+
+    #[test]
+    fn capture_outer_and_unbound_variables() {
+        check("let a = 1\n|x| x + a + b", &[&["a", "b"]]);
+    }
+
+    #[test]
+    fn capture_each_variable_once() {
+        check("|| a + a * b", &[&["a", "b"]]);
+    }
+
+    #[test]
+    fn dont_capture_locals() {
+        check("|a| { let b = a\n b + c }", &[&["c"]]);
+    }
+
+    #[test]
+    fn capture_variable_shadowed_by_own_definition() {
+        check("|| { let a = a\n a }", &[&["a"]]);
+    }
+
+    #[test]
+    fn capture_assignment_target() {
+        check("let count = 0\nlet inc = || count += 1", &[&["count"]]);
+    }
+
+    #[test]
+    fn capture_in_nested_closures() {
+        check("|x| |y| x + y + z", &[&["x", "z"], &["z"]]);
+    }
+
+    #[test]
+    fn dont_capture_match_bindings() {
+        check("|s| match s { Some(v) => v + w, _ => v }", &[&["w", "v"]]);
+    }
+
+    #[test]
+    fn dont_capture_fn_defined_inside_closure() {
+        check("|| { fn f(n) { f(n - 1) + m }\n f(0) }", &[&["m"]]);
+    }
+
+    #[test]
+    fn dont_capture_imports_inside_closure() {
+        check("|| { use shapes::area\n area(s) }", &[&["s"]]);
+    }
+
+    #[test]
+    fn capture_in_fn_closure() {
+        check("let double = fn(x) => x * factor", &[&["factor"]]);
+    }
+
+    #[test]
+    fn dont_capture_paths() {
+        check("|| shapes::area(s)", &[&["s"]]);
+    }
+
+    #[test]
+    fn query_captures() {
+        let root = ast::Root::cast(parser::parse("let f = |x| x + y\nf").syntax()).unwrap();
+        let (database, stmts) = crate::lower(&root);
+
+        let Stmt::VariableDef { value, .. } = &stmts[0] else {
+            panic!("expected a variable definition");
+        };
+
+        assert_eq!(database.captures(value), Some(&["y".into()][..]));
+
+        let Stmt::Expr(expr) = &stmts[1] else {
+            panic!("expected an expression");
+        };
+
+        assert!(matches!(expr, Expr::VariableRef { .. }));
+        assert_eq!(database.captures(expr), None);
+    }
+}
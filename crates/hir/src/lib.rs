@@ -3,28 +3,148 @@
 mod database;
 pub use database::Database;
 
+mod module_tree;
+pub use module_tree::{Module, ModuleError, ModuleTree};
+
 use la_arena::Idx;
 use smol_str::SmolStr;
+use std::fmt;
+use text_size::TextRange;
 
 type ExprIdx = Idx<Expr>;
+type ModuleIdx = Idx<Module>;
+type PatIdx = Idx<Pat>;
+type StmtIdx = Idx<Stmt>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Stmt {
-    VariableDef { name: SmolStr, value: Expr },
+    EnumDef {
+        name: SmolStr,
+        variants: Vec<Variant>,
+    },
+    FnDef {
+        name: SmolStr,
+        params: Vec<Param>,
+        /// is `None` if the return type isn’t annotated
+        ret_ty: Option<TypeRef>,
+        body: Expr,
+    },
+    /// declares a module whose items are in another file, see `ModuleTree`
+    ModDef {
+        name: SmolStr,
+    },
+    StructDef {
+        name: SmolStr,
+        fields: Vec<FieldDef>,
+    },
+    UseDef {
+        path: Path,
+    },
+    VariableDef {
+        name: SmolStr,
+        /// is `None` if the type isn’t annotated
+        ty: Option<TypeRef>,
+        value: Expr,
+    },
     Expr(Expr),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: SmolStr,
+    /// is `None` if the type isn’t annotated
+    pub ty: Option<TypeRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: SmolStr,
+    /// is empty if the variant doesn’t carry any values
+    pub fields: Vec<Param>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDef {
+    pub name: SmolStr,
+    /// is `None` if the type is missing
+    pub ty: Option<TypeRef>,
+}
+
+/// A type as it’s written in an annotation, before anything has checked that it exists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Named(SmolStr),
+    Path(Path),
+    Tuple(Vec<TypeRef>),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Expr {
     Missing,
+    Array {
+        elements: Vec<ExprIdx>,
+    },
+    Assign {
+        /// is the operator of a compound assignment, e.g. `Add` for ‘+=’, or `None` for ‘=’
+        op: Option<BinaryOp>,
+        target: ExprIdx,
+        value: ExprIdx,
+    },
     Binary {
         op: BinaryOp,
         lhs: ExprIdx,
         rhs: ExprIdx,
     },
-    Literal {
-        /// is `None` if the number is too big to fit in a u64
-        n: Option<u64>,
+    Block {
+        stmts: Vec<StmtIdx>,
+        /// is `None` if the block doesn’t end in an expression, e.g. `{ a; }`
+        tail: Option<ExprIdx>,
+    },
+    Break {
+        /// is `None` if the ‘break’ doesn’t carry a value
+        value: Option<ExprIdx>,
+    },
+    Call {
+        callee: ExprIdx,
+        args: Vec<ExprIdx>,
+    },
+    /// The variables it captures are computed after lowering, see `Database::captures`.
+    Closure {
+        params: Vec<Param>,
+        body: ExprIdx,
+    },
+    Continue,
+    Field {
+        base: ExprIdx,
+        /// is `None` if the field’s name is missing, e.g. while `p.` is being typed
+        field: Option<SmolStr>,
+    },
+    If {
+        condition: ExprIdx,
+        then_branch: ExprIdx,
+        /// is `None` if there’s no ‘else’ at all
+        else_branch: Option<ExprIdx>,
+    },
+    Index {
+        base: ExprIdx,
+        index: ExprIdx,
+    },
+    Literal(Literal),
+    Loop {
+        body: ExprIdx,
+    },
+    Match {
+        scrutinee: ExprIdx,
+        arms: Vec<MatchArm>,
+    },
+    Path(Path),
+    Record {
+        /// is the name of the struct being constructed
+        name: SmolStr,
+        fields: Vec<RecordField>,
+    },
+    Tuple {
+        fields: Vec<ExprIdx>,
     },
     Unary {
         op: UnaryOp,
@@ -33,6 +153,81 @@ pub enum Expr {
     VariableRef {
         var: SmolStr,
     },
+    While {
+        condition: ExprIdx,
+        body: ExprIdx,
+    },
+}
+
+/// A `::`-separated path to an item in another module, e.g. `shapes::area`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub segments: Vec<SmolStr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchArm {
+    pub pat: PatIdx,
+    /// is `None` if the arm doesn’t have an ‘if’ guard
+    pub guard: Option<ExprIdx>,
+    pub body: ExprIdx,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pat {
+    Missing,
+    /// matches anything without binding it, i.e. `_`
+    Wildcard,
+    Binding {
+        name: SmolStr,
+    },
+    Literal(Literal),
+    Tuple {
+        fields: Vec<PatIdx>,
+    },
+    Variant {
+        name: SmolStr,
+        fields: Vec<PatIdx>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordField {
+    pub name: SmolStr,
+    pub value: ExprIdx,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Literal {
+    /// is `None` if the integer is malformed or too big to fit in a u64
+    Int(Option<u64>),
+    /// is `None` if the float’s exponent is malformed
+    Float(Option<Float>),
+    /// is `None` if the string is unterminated or contains an invalid escape sequence
+    String(Option<String>),
+    Bool(bool),
+}
+
+/// The value of a float literal, stored as its bits so that the HIR can be compared for equality.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Float(u64);
+
+impl Float {
+    pub fn value(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+}
+
+impl From<f64> for Float {
+    fn from(value: f64) -> Self {
+        Self(value.to_bits())
+    }
+}
+
+impl fmt::Debug for Float {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value(), f)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -40,16 +235,76 @@ pub enum BinaryOp {
     Add,
     Sub,
     Mul,
+    /// Both operands must be of the same numeric kind, since integers and floats are never
+    /// implicitly converted into one another. Integer division truncates towards zero, and
+    /// dividing by zero is an error. Float division follows IEEE 754, so dividing by zero produces
+    /// an infinity (or NaN for `0.0 / 0.0`) instead.
     Div,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    /// short-circuits, so the rhs is only evaluated if the lhs is true
+    And,
+    /// short-circuits, so the rhs is only evaluated if the lhs is false
+    Or,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnaryOp {
     Neg,
+    Not,
+}
+
+/// A problem found while lowering, which the parser and validation can’t catch since they only
+/// look at one node at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LowerError {
+    kind: LowerErrorKind,
+    range: TextRange,
+}
+
+impl fmt::Display for LowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error at {}..{}: {}",
+            u32::from(self.range.start()),
+            u32::from(self.range.end()),
+            self.kind,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LowerErrorKind {
+    FieldAlreadyDeclared { name: SmolStr },
+    FieldSpecifiedTwice { name: SmolStr },
+    VariantAlreadyDeclared { name: SmolStr },
+}
+
+impl fmt::Display for LowerErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldAlreadyDeclared { name } => write!(f, "field ‘{name}’ is already declared"),
+            Self::FieldSpecifiedTwice { name } => {
+                write!(f, "field ‘{name}’ is specified more than once")
+            }
+            Self::VariantAlreadyDeclared { name } => {
+                write!(f, "variant ‘{name}’ is already declared")
+            }
+        }
+    }
 }
 
+/// Lowers a syntax tree to HIR. Lowering recurses as deeply as the tree is nested, which the
+/// parser bounds with `parser::ParseConfig::max_depth`. A program split across several files is
+/// lowered one module at a time, with the modules’ roots coming from a `ModuleTree`.
 pub fn lower(ast: &ast::Root) -> (Database, Vec<Stmt>) {
     let mut db = Database::default();
-    let stmts = ast.stmts().filter_map(|stmt| db.lower_stmt(stmt)).collect();
+    let stmts: Vec<_> = ast.stmts().filter_map(|stmt| db.lower_stmt(stmt)).collect();
+    db.compute_captures(&stmts);
     (db, stmts)
 }
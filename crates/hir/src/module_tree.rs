@@ -0,0 +1,421 @@
+use crate::ModuleIdx;
+use crate::database::lower_name;
+use la_arena::Arena;
+use parser::Parse;
+use smol_str::SmolStr;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io, iter, ops};
+use text_size::TextRange;
+
+/// The modules making up a program, starting from the file it’s run from. Every `mod` declaration
+/// at the top level of a file loads the module in the file of that name next to the declaring
+/// module’s file, e.g. `mod shapes` in `src/main.chou` loads `src/shapes.chou`; one anywhere else
+/// is an error. Each file is loaded once, by the first module to declare it, so two modules
+/// declaring the same one is an error. Each module can then be lowered on its own with `lower`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleTree {
+    modules: Arena<Module>,
+    root: ModuleIdx,
+    errors: Vec<ModuleError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    /// is `None` for the root module
+    pub name: Option<SmolStr>,
+    pub file: PathBuf,
+    /// is `None` for the root module
+    pub parent: Option<ModuleIdx>,
+    /// the modules declared in this one, in the order they’re declared
+    pub children: Vec<ModuleIdx>,
+    pub parse: Parse,
+}
+
+impl Module {
+    pub fn root(&self) -> ast::Root {
+        ast::Root::cast(self.parse.syntax()).unwrap()
+    }
+}
+
+impl ModuleTree {
+    /// Loads the module in `file` along with every module declared in it, recursively. Only
+    /// failing to read `file` itself is an error here; problems with the modules it declares,
+    /// e.g. missing files, are reported by `errors`.
+    pub fn load(file: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(file)?;
+
+        Ok(Self::build(file.to_path_buf(), &text, |file| {
+            fs::read_to_string(file).ok()
+        }))
+    }
+
+    /// Builds the tree from the root module’s `file` and its `text`, using `read_file` to read the
+    /// files of the modules it declares, which returns `None` if a file can’t be read.
+    fn build(
+        file: PathBuf,
+        text: &str,
+        mut read_file: impl FnMut(&Path) -> Option<String>,
+    ) -> Self {
+        let mut modules = Arena::new();
+        let root = modules.alloc(Module {
+            name: None,
+            file,
+            parent: None,
+            children: Vec::new(),
+            parse: parser::parse(text),
+        });
+
+        let mut tree = Self {
+            modules,
+            root,
+            errors: Vec::new(),
+        };
+
+        // The module whose declaration loaded each file. The root module’s file isn’t in here, but
+        // it’s nested in every module, so declaring it is caught as a cycle.
+        let mut declared_by: HashMap<PathBuf, ModuleIdx> = HashMap::new();
+
+        // We work through the modules with a queue instead of recursing into each one as it’s
+        // declared, so that however deeply modules are nested this can’t overflow the stack.
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(idx) = queue.pop_front() {
+            let mut seen = HashSet::new();
+
+            let syntax = tree.modules[idx].parse.syntax();
+
+            // We look for `mod` declarations everywhere rather than only among the module’s
+            // statements, so that one nested in e.g. a function body is reported instead of
+            // being ignored.
+            for node in syntax.descendants() {
+                let is_top_level = node.parent().as_ref() == Some(&syntax);
+
+                let Some(ast::Stmt::ModDef(mod_def)) = ast::Stmt::cast(node) else {
+                    continue;
+                };
+
+                // A missing name has already been reported by the parser.
+                let (Some(mod_kw), Some(name)) = (mod_def.mod_kw(), mod_def.name()) else {
+                    continue;
+                };
+
+                let range = TextRange::new(mod_kw.text_range().start(), name.text_range().end());
+                let name = lower_name(&name);
+
+                if !is_top_level {
+                    tree.error(idx, ModuleErrorKind::NotAtTopLevel { name }, range);
+                    continue;
+                }
+
+                if !seen.insert(name.clone()) {
+                    tree.error(idx, ModuleErrorKind::AlreadyDeclared { name }, range);
+                    continue;
+                }
+
+                let file = tree.modules[idx]
+                    .file
+                    .with_file_name(format!("{name}.chou"));
+
+                if tree
+                    .ancestors(idx)
+                    .any(|ancestor| tree.modules[ancestor].file == file)
+                {
+                    tree.error(idx, ModuleErrorKind::Cycle { name, file }, range);
+                    continue;
+                }
+
+                if let Some(&owner) = declared_by.get(&file) {
+                    let owner = tree.modules[owner].file.clone();
+                    tree.error(
+                        idx,
+                        ModuleErrorKind::AlreadyLoaded { name, file, owner },
+                        range,
+                    );
+                    continue;
+                }
+
+                let Some(text) = read_file(&file) else {
+                    tree.error(idx, ModuleErrorKind::FileNotFound { name, file }, range);
+                    continue;
+                };
+
+                declared_by.insert(file.clone(), idx);
+
+                let child = tree.modules.alloc(Module {
+                    name: Some(name),
+                    file,
+                    parent: Some(idx),
+                    children: Vec::new(),
+                    parse: parser::parse(&text),
+                });
+
+                tree.modules[idx].children.push(child);
+                queue.push_back(child);
+            }
+        }
+
+        tree
+    }
+
+    pub fn root(&self) -> ModuleIdx {
+        self.root
+    }
+
+    pub fn modules(&self) -> impl Iterator<Item = (ModuleIdx, &Module)> {
+        self.modules.iter()
+    }
+
+    pub fn errors(&self) -> &[ModuleError] {
+        &self.errors
+    }
+
+    /// Returns the module at `idx` followed by the modules it’s nested in, innermost first.
+    fn ancestors(&self, idx: ModuleIdx) -> impl Iterator<Item = ModuleIdx> {
+        iter::successors(Some(idx), |&idx| self.modules[idx].parent)
+    }
+
+    fn error(&mut self, module: ModuleIdx, kind: ModuleErrorKind, range: TextRange) {
+        self.errors.push(ModuleError {
+            kind,
+            file: self.modules[module].file.clone(),
+            range,
+        });
+    }
+}
+
+impl ops::Index<ModuleIdx> for ModuleTree {
+    type Output = Module;
+
+    fn index(&self, idx: ModuleIdx) -> &Self::Output {
+        &self.modules[idx]
+    }
+}
+
+/// A problem with a `mod` declaration, whose range is within the file of the module declaring it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleError {
+    kind: ModuleErrorKind,
+    file: PathBuf,
+    range: TextRange,
+}
+
+impl fmt::Display for ModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: error at {}..{}: {}",
+            self.file.display(),
+            u32::from(self.range.start()),
+            u32::from(self.range.end()),
+            self.kind,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ModuleErrorKind {
+    AlreadyDeclared {
+        name: SmolStr,
+    },
+    AlreadyLoaded {
+        name: SmolStr,
+        file: PathBuf,
+        owner: PathBuf,
+    },
+    Cycle {
+        name: SmolStr,
+        file: PathBuf,
+    },
+    FileNotFound {
+        name: SmolStr,
+        file: PathBuf,
+    },
+    NotAtTopLevel {
+        name: SmolStr,
+    },
+}
+
+impl fmt::Display for ModuleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyDeclared { name } => write!(f, "module ‘{name}’ is already declared"),
+            Self::AlreadyLoaded { name, file, owner } => write!(
+                f,
+                "module ‘{name}’ would include ‘{}’, which ‘{}’ already includes",
+                file.display(),
+                owner.display(),
+            ),
+            Self::Cycle { name, file } => write!(
+                f,
+                "module ‘{name}’ would include ‘{}’, which it’s already nested in",
+                file.display(),
+            ),
+            Self::FileNotFound { name, file } => {
+                write!(f, "can’t find ‘{}’ for module ‘{name}’", file.display())
+            }
+            Self::NotAtTopLevel { name } => write!(
+                f,
+                "module ‘{name}’ has to be declared at the top level of its file",
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn build(files: &[(&str, &str)]) -> ModuleTree {
+        let files: HashMap<_, _> = files
+            .iter()
+            .map(|&(file, text)| (PathBuf::from(file), text.to_string()))
+            .collect();
+
+        ModuleTree::build(
+            "src/main.chou".into(),
+            &files[Path::new("src/main.chou")],
+            |file| files.get(file).cloned(),
+        )
+    }
+
+    fn check_errors(files: &[(&str, &str)], expected_errors: &[&str]) {
+        let tree = build(files);
+        let errors: Vec<_> = tree.errors().iter().map(ToString::to_string).collect();
+        assert_eq!(errors, expected_errors);
+    }
+
+    #[test]
+    fn load_nested_modules() {
+        let tree = build(&[
+            ("src/main.chou", "mod shapes\nmod util"),
+            ("src/shapes.chou", "mod circle"),
+            ("src/circle.chou", "let r = 1"),
+            ("src/util.chou", ""),
+        ]);
+
+        assert!(tree.errors().is_empty());
+
+        let root = &tree[tree.root()];
+        assert_eq!(root.name, None);
+        assert_eq!(root.parent, None);
+        assert_eq!(root.children.len(), 2);
+
+        let shapes = &tree[root.children[0]];
+        assert_eq!(shapes.name.as_deref(), Some("shapes"));
+        assert_eq!(shapes.file, Path::new("src/shapes.chou"));
+        assert_eq!(shapes.parent, Some(tree.root()));
+
+        let circle = &tree[shapes.children[0]];
+        assert_eq!(circle.name.as_deref(), Some("circle"));
+        assert_eq!(circle.root().stmts().count(), 1);
+
+        let util = &tree[root.children[1]];
+        assert_eq!(util.name.as_deref(), Some("util"));
+        assert!(util.children.is_empty());
+
+        assert_eq!(tree.modules().count(), 4);
+    }
+
+    #[test]
+    fn report_missing_file() {
+        check_errors(
+            &[("src/main.chou", "let a = 1\nmod shapes")],
+            &["src/main.chou: error at 10..20: can’t find ‘src/shapes.chou’ for module ‘shapes’"],
+        );
+    }
+
+    #[test]
+    fn report_cycle() {
+        check_errors(
+            &[
+                ("src/main.chou", "mod a"),
+                ("src/a.chou", "mod b"),
+                ("src/b.chou", "mod main"),
+            ],
+            &[
+                "src/b.chou: error at 0..8: module ‘main’ would include ‘src/main.chou’, which it’s \
+               already nested in",
+            ],
+        );
+    }
+
+    #[test]
+    fn report_module_declaring_itself() {
+        check_errors(
+            &[("src/main.chou", "mod main")],
+            &[
+                "src/main.chou: error at 0..8: module ‘main’ would include ‘src/main.chou’, which it’s \
+               already nested in",
+            ],
+        );
+    }
+
+    #[test]
+    fn report_module_declared_twice() {
+        check_errors(
+            &[("src/main.chou", "mod a\nmod a"), ("src/a.chou", "")],
+            &["src/main.chou: error at 6..11: module ‘a’ is already declared"],
+        );
+    }
+
+    #[test]
+    fn report_module_declared_by_two_modules() {
+        let tree = build(&[
+            ("src/main.chou", "mod a\nmod b"),
+            ("src/a.chou", "mod shared"),
+            ("src/b.chou", "mod shared"),
+            ("src/shared.chou", "let s = 1"),
+        ]);
+
+        let errors: Vec<_> = tree.errors().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            [
+                "src/b.chou: error at 0..10: module ‘shared’ would include ‘src/shared.chou’, which \
+                 ‘src/a.chou’ already includes",
+            ],
+        );
+
+        let shared: Vec<_> = tree
+            .modules()
+            .filter(|(_, module)| module.file == Path::new("src/shared.chou"))
+            .collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!(tree.modules().count(), 4);
+    }
+
+    #[test]
+    fn report_module_declared_below_top_level() {
+        check_errors(
+            &[
+                (
+                    "src/main.chou",
+                    "fn f() {\n  mod shapes\n}\nlet a = { mod util; 1 }",
+                ),
+                ("src/shapes.chou", ""),
+                ("src/util.chou", ""),
+            ],
+            &[
+                "src/main.chou: error at 11..21: module ‘shapes’ has to be declared at the top \
+                 level of its file",
+                "src/main.chou: error at 34..42: module ‘util’ has to be declared at the top level \
+                 of its file",
+            ],
+        );
+    }
+
+    #[test]
+    fn error_range_excludes_doc_comment_and_semicolon() {
+        check_errors(
+            &[("src/main.chou", "## Shapes.\nmod shapes;")],
+            &["src/main.chou: error at 11..21: can’t find ‘src/shapes.chou’ for module ‘shapes’"],
+        );
+    }
+
+    #[test]
+    fn ignore_mod_def_without_name() {
+        check_errors(&[("src/main.chou", "mod")], &[]);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::EscapeError;
+use std::fmt;
+use text_size::TextRange;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub range: TextRange,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    /// A character that can’t start any token, e.g. `$`.
+    InvalidCharacter(char),
+    /// A string literal without its closing quote. The range covers the opening quote.
+    UnterminatedString,
+    /// A block comment without its closing `]#`. The range covers the opening `#[`.
+    UnterminatedBlockComment,
+    InvalidEscape(EscapeError),
+}
+
+impl LexErrorKind {
+    /// Returns a code identifying this kind of error. Codes never change meaning and are never
+    /// reused, so they’re safe to filter diagnostics by.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::InvalidCharacter(_) => "L0001",
+            Self::UnterminatedString => "L0002",
+            Self::UnterminatedBlockComment => "L0003",
+            Self::InvalidEscape(_) => "L0004",
+        }
+    }
+}
+
+impl fmt::Display for LexErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter(c) => write!(f, "invalid character ‘{}’", c.escape_debug()),
+            Self::UnterminatedString => write!(f, "string literal is missing its closing ‘\"’"),
+            Self::UnterminatedBlockComment => {
+                write!(f, "block comment is missing its closing ‘]#’")
+            }
+            Self::InvalidEscape(error) => write!(f, "{error}"),
+        }
+    }
+}
@@ -3,6 +3,12 @@
 mod token_kind;
 pub use token_kind::TokenKind;
 
+mod lex_error;
+pub use lex_error::{LexError, LexErrorKind};
+
+mod unescape;
+pub use unescape::{EscapeError, string_contents, unescape};
+
 use logos::Logos;
 use std::ops::Range as StdRange;
 use text_size::{TextRange, TextSize};
@@ -10,6 +16,7 @@ use text_size::{TextRange, TextSize};
 #[derive(Debug, Clone)]
 pub struct Lexer<'a> {
     inner: logos::Lexer<'a, TokenKind>,
+    errors: Vec<LexError>,
 }
 
 impl<'a> Lexer<'a> {
@@ -17,8 +24,64 @@ impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
         Self {
             inner: TokenKind::lexer(input),
+            errors: Vec::new(),
         }
     }
+
+    /// Returns the errors found in the tokens lexed so far, in source order.
+    #[inline]
+    pub fn into_errors(self) -> Vec<LexError> {
+        self.errors
+    }
+
+    fn validate(&mut self, kind: TokenKind, text: &str, range: TextRange) {
+        match kind {
+            TokenKind::Error => self.errors.push(LexError {
+                kind: LexErrorKind::InvalidCharacter(text.chars().next().unwrap()),
+                range,
+            }),
+            TokenKind::String => self.validate_string(text, range),
+            TokenKind::Comment => {
+                let is_unterminated_block_comment = text
+                    .strip_prefix("#[")
+                    .is_some_and(|rest| token_kind::block_comment_len(rest).is_none());
+
+                if is_unterminated_block_comment {
+                    self.errors.push(LexError {
+                        kind: LexErrorKind::UnterminatedBlockComment,
+                        range: TextRange::at(range.start(), TextSize::of("#[")),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_string(&mut self, text: &str, range: TextRange) {
+        let quote_len = TextSize::of('"');
+
+        let contents = match string_contents(text) {
+            Some(contents) => contents,
+            None => {
+                self.errors.push(LexError {
+                    kind: LexErrorKind::UnterminatedString,
+                    range: TextRange::at(range.start(), quote_len),
+                });
+
+                // There’s no closing quote to strip, so we check everything after the opening one.
+                &text[1..]
+            }
+        };
+
+        let contents_start = range.start() + quote_len;
+
+        unescape(contents, |error, range| {
+            self.errors.push(LexError {
+                kind: LexErrorKind::InvalidEscape(error),
+                range: range + contents_start,
+            });
+        });
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -34,6 +97,8 @@ impl<'a> Iterator for Lexer<'a> {
             TextRange::new(start, end)
         };
 
+        self.validate(kind, text, range);
+
         Some(Self::Item { kind, text, range })
     }
 }
@@ -44,3 +109,117 @@ pub struct Token<'a> {
     pub text: &'a str,
     pub range: TextRange,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(input: &str, expected_errors: &[(LexErrorKind, StdRange<u32>)]) {
+        let mut lexer = Lexer::new(input);
+        lexer.by_ref().for_each(drop);
+
+        let expected_errors: Vec<_> = expected_errors
+            .iter()
+            .map(|(kind, range)| LexError {
+                kind: *kind,
+                range: TextRange::new(range.start.into(), range.end.into()),
+            })
+            .collect();
+
+        assert_eq!(lexer.into_errors(), expected_errors);
+    }
+
+    #[test]
+    fn lex_without_errors() {
+        check("let a = \"tab\\there\" #[ ok ]#", &[]);
+    }
+
+    #[test]
+    fn lex_invalid_character() {
+        check("a $ b", &[(LexErrorKind::InvalidCharacter('$'), 2..3)]);
+    }
+
+    #[test]
+    fn lex_invalid_non_ascii_character() {
+        check(
+            "1 \u{2212} 2",
+            &[(LexErrorKind::InvalidCharacter('\u{2212}'), 2..5)],
+        );
+    }
+
+    #[test]
+    fn lex_unterminated_string() {
+        check(
+            r#"let a = "foo"#,
+            &[(LexErrorKind::UnterminatedString, 8..9)],
+        );
+    }
+
+    #[test]
+    fn lex_unterminated_string_ending_in_escaped_quote() {
+        check(r#""foo\""#, &[(LexErrorKind::UnterminatedString, 0..1)]);
+    }
+
+    #[test]
+    fn lex_unterminated_block_comment() {
+        check(
+            "1 #[ a #[ b ]#",
+            &[(LexErrorKind::UnterminatedBlockComment, 2..4)],
+        );
+    }
+
+    #[test]
+    fn lex_unknown_escape() {
+        check(
+            r#"1 + "a\qb""#,
+            &[(
+                LexErrorKind::InvalidEscape(EscapeError::UnknownEscape),
+                6..8,
+            )],
+        );
+    }
+
+    #[test]
+    fn lex_invalid_unicode_code_point() {
+        check(
+            r#""ok \u{110000}""#,
+            &[(
+                LexErrorKind::InvalidEscape(EscapeError::InvalidUnicodeCodePoint),
+                4..14,
+            )],
+        );
+    }
+
+    #[test]
+    fn lex_multiple_errors_in_one_string() {
+        check(
+            r#""\x\u{}"#,
+            &[
+                (LexErrorKind::UnterminatedString, 0..1),
+                (
+                    LexErrorKind::InvalidEscape(EscapeError::UnknownEscape),
+                    1..3,
+                ),
+                (
+                    LexErrorKind::InvalidEscape(EscapeError::EmptyUnicodeEscape),
+                    3..7,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_errors_in_source_order() {
+        check(
+            "@ \"\\q\" ^",
+            &[
+                (LexErrorKind::InvalidCharacter('@'), 0..1),
+                (
+                    LexErrorKind::InvalidEscape(EscapeError::UnknownEscape),
+                    3..5,
+                ),
+                (LexErrorKind::InvalidCharacter('^'), 7..8),
+            ],
+        );
+    }
+}
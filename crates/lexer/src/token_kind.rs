@@ -13,12 +13,63 @@ pub enum TokenKind {
     #[token("let")]
     LetKw,
 
-    #[regex(r"[[:alpha:]][[:alnum:]]*")]
+    #[token("struct")]
+    StructKw,
+
+    #[token("enum")]
+    EnumKw,
+
+    #[token("mod")]
+    ModKw,
+
+    #[token("use")]
+    UseKw,
+
+    #[token("if")]
+    IfKw,
+
+    #[token("else")]
+    ElseKw,
+
+    #[token("match")]
+    MatchKw,
+
+    #[token("while")]
+    WhileKw,
+
+    #[token("loop")]
+    LoopKw,
+
+    #[token("break")]
+    BreakKw,
+
+    #[token("continue")]
+    ContinueKw,
+
+    #[token("true")]
+    TrueKw,
+
+    #[token("false")]
+    FalseKw,
+
+    #[regex(r"[\p{XID_Start}_]\p{XID_Continue}*")]
     Ident,
 
-    #[regex(r"[[:digit:]]+")]
+    // Radix-prefixed literals take any alphanumeric characters so that invalid digits, e.g. the `2`
+    // in `0b102`, can be reported precisely instead of splitting the literal into several tokens.
+    #[regex(r"[[:digit:]][[:digit:]_]*")]
+    #[regex(r"0[xXoObB][[:alnum:]_]*")]
     Number,
 
+    // An exponent without any digits, e.g. `1e+`, still lexes as a float so that it can be reported
+    // as malformed instead of being split into several tokens.
+    #[regex(r"[[:digit:]][[:digit:]_]*\.[[:digit:]][[:digit:]_]*([eE][+-]?[[:digit:]_]*)?")]
+    #[regex(r"[[:digit:]][[:digit:]_]*[eE][+-]?[[:digit:]_]*")]
+    Float,
+
+    #[regex(r#""([^"\\]|\\(.|\n))*\\?"?"#)]
+    String,
+
     #[token("+")]
     Plus,
 
@@ -34,6 +85,69 @@ pub enum TokenKind {
     #[token("=")]
     Equals,
 
+    #[token("+=")]
+    PlusEquals,
+
+    #[token("-=")]
+    MinusEquals,
+
+    #[token("*=")]
+    StarEquals,
+
+    #[token("/=")]
+    SlashEquals,
+
+    #[token("==")]
+    EqualsEquals,
+
+    #[token("!=")]
+    BangEquals,
+
+    #[token("<")]
+    Less,
+
+    #[token("<=")]
+    LessEquals,
+
+    #[token(">")]
+    Greater,
+
+    #[token(">=")]
+    GreaterEquals,
+
+    #[token("&&")]
+    AndAnd,
+
+    #[token("||")]
+    OrOr,
+
+    #[token("|")]
+    Pipe,
+
+    #[token("!")]
+    Bang,
+
+    #[token(",")]
+    Comma,
+
+    #[token(".")]
+    Dot,
+
+    #[token(":")]
+    Colon,
+
+    #[token("::")]
+    ColonColon,
+
+    #[token("->")]
+    Arrow,
+
+    #[token("=>")]
+    FatArrow,
+
+    #[token(";")]
+    Semicolon,
+
     #[token("(")]
     LParen,
 
@@ -46,16 +160,28 @@ pub enum TokenKind {
     #[token("}")]
     RBrace,
 
-    #[regex(r"#.*")]
+    #[token("[")]
+    LBracket,
+
+    #[token("]")]
+    RBracket,
+
+    // `##` starts a doc comment and `#[` starts a block comment, so neither can start a line
+    // comment.
+    #[regex(r"#([^#\[\n].*)?")]
+    #[token("#[", block_comment)]
     Comment,
 
+    #[regex(r"##.*")]
+    DocComment,
+
     Error,
 }
 
 impl TokenKind {
     #[inline]
     pub fn is_trivia(self) -> bool {
-        matches!(self, Self::Whitespace | Self::Comment)
+        matches!(self, Self::Whitespace | Self::Comment | Self::DocComment)
     }
 }
 
@@ -65,23 +191,97 @@ impl fmt::Display for TokenKind {
             Self::Whitespace => "whitespace",
             Self::FnKw => "‘fn’",
             Self::LetKw => "‘let’",
+            Self::StructKw => "‘struct’",
+            Self::EnumKw => "‘enum’",
+            Self::ModKw => "‘mod’",
+            Self::UseKw => "‘use’",
+            Self::IfKw => "‘if’",
+            Self::ElseKw => "‘else’",
+            Self::MatchKw => "‘match’",
+            Self::WhileKw => "‘while’",
+            Self::LoopKw => "‘loop’",
+            Self::BreakKw => "‘break’",
+            Self::ContinueKw => "‘continue’",
+            Self::TrueKw => "‘true’",
+            Self::FalseKw => "‘false’",
             Self::Ident => "identifier",
             Self::Number => "number",
+            Self::Float => "float",
+            Self::String => "string",
             Self::Plus => "‘+’",
             Self::Minus => "‘-’",
             Self::Star => "‘*’",
             Self::Slash => "‘/’",
             Self::Equals => "‘=’",
+            Self::PlusEquals => "‘+=’",
+            Self::MinusEquals => "‘-=’",
+            Self::StarEquals => "‘*=’",
+            Self::SlashEquals => "‘/=’",
+            Self::EqualsEquals => "‘==’",
+            Self::BangEquals => "‘!=’",
+            Self::Less => "‘<’",
+            Self::LessEquals => "‘<=’",
+            Self::Greater => "‘>’",
+            Self::GreaterEquals => "‘>=’",
+            Self::AndAnd => "‘&&’",
+            Self::OrOr => "‘||’",
+            Self::Pipe => "‘|’",
+            Self::Bang => "‘!’",
+            Self::Comma => "‘,’",
+            Self::Dot => "‘.’",
+            Self::Colon => "‘:’",
+            Self::ColonColon => "‘::’",
+            Self::Arrow => "‘->’",
+            Self::FatArrow => "‘=>’",
+            Self::Semicolon => "‘;’",
             Self::LParen => "‘(’",
             Self::RParen => "‘)’",
             Self::LBrace => "‘{’",
             Self::RBrace => "‘}’",
+            Self::LBracket => "‘[’",
+            Self::RBracket => "‘]’",
             Self::Comment => "comment",
+            Self::DocComment => "doc comment",
             Self::Error => "an unrecognized token",
         })
     }
 }
 
+/// Skips past the rest of a block comment, whose opening `#[` has already been lexed. An
+/// unterminated block comment extends to the end of the input.
+fn block_comment(lexer: &mut logos::Lexer<TokenKind>) {
+    let remainder = lexer.remainder();
+    lexer.bump(block_comment_len(remainder).unwrap_or(remainder.len()));
+}
+
+/// Returns the length of the rest of a block comment following its opening `#[`, up to and
+/// including its closing `]#`, or `None` if the comment is unterminated. Block comments nest, so
+/// every `#[` inside one needs its own `]#`.
+pub(crate) fn block_comment_len(text: &str) -> Option<usize> {
+    let mut depth = 1;
+    let mut idx = 0;
+
+    while idx < text.len() {
+        let rest = &text[idx..];
+
+        if rest.starts_with("#[") {
+            depth += 1;
+            idx += 2;
+        } else if rest.starts_with("]#") {
+            depth -= 1;
+            idx += 2;
+
+            if depth == 0 {
+                return Some(idx);
+            }
+        } else {
+            idx += rest.chars().next().unwrap().len_utf8();
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +294,14 @@ mod tests {
         assert_eq!(token.text, input);
     }
 
+    fn check_tokens(input: &str, expected: &[(TokenKind, &str)]) {
+        let tokens: Vec<_> = Lexer::new(input)
+            .map(|token| (token.kind, token.text))
+            .collect();
+
+        assert_eq!(tokens, expected);
+    }
+
     #[test]
     fn lex_spaces_and_newlines() {
         check("  \n ", TokenKind::Whitespace);
@@ -109,6 +317,76 @@ mod tests {
         check("let", TokenKind::LetKw);
     }
 
+    #[test]
+    fn lex_struct_keyword() {
+        check("struct", TokenKind::StructKw);
+    }
+
+    #[test]
+    fn lex_enum_keyword() {
+        check("enum", TokenKind::EnumKw);
+    }
+
+    #[test]
+    fn lex_mod_keyword() {
+        check("mod", TokenKind::ModKw);
+    }
+
+    #[test]
+    fn lex_use_keyword() {
+        check("use", TokenKind::UseKw);
+    }
+
+    #[test]
+    fn lex_if_keyword() {
+        check("if", TokenKind::IfKw);
+    }
+
+    #[test]
+    fn lex_else_keyword() {
+        check("else", TokenKind::ElseKw);
+    }
+
+    #[test]
+    fn lex_match_keyword() {
+        check("match", TokenKind::MatchKw);
+    }
+
+    #[test]
+    fn lex_while_keyword() {
+        check("while", TokenKind::WhileKw);
+    }
+
+    #[test]
+    fn lex_loop_keyword() {
+        check("loop", TokenKind::LoopKw);
+    }
+
+    #[test]
+    fn lex_break_keyword() {
+        check("break", TokenKind::BreakKw);
+    }
+
+    #[test]
+    fn lex_continue_keyword() {
+        check("continue", TokenKind::ContinueKw);
+    }
+
+    #[test]
+    fn lex_true_keyword() {
+        check("true", TokenKind::TrueKw);
+    }
+
+    #[test]
+    fn lex_false_keyword() {
+        check("false", TokenKind::FalseKw);
+    }
+
+    #[test]
+    fn lex_identifier_starting_with_keyword() {
+        check("iffy", TokenKind::Ident);
+    }
+
     #[test]
     fn lex_alphabetic_identifier() {
         check("abcd", TokenKind::Ident);
@@ -129,11 +407,164 @@ mod tests {
         check("x", TokenKind::Ident);
     }
 
+    #[test]
+    fn lex_snake_case_identifier() {
+        check("snake_case_1", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_identifier_starting_with_underscore() {
+        check("_unused", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_underscore_identifier() {
+        check("_", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_non_ascii_identifier() {
+        check("café", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_identifier_with_combining_character() {
+        check("cafe\u{301}", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_mixed_script_identifier() {
+        check("naïve_日本語_Ελληνικά", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_identifier_with_confusable_cyrillic_letter() {
+        // The ‘а’ is U+0430 CYRILLIC SMALL LETTER A, which looks just like a Latin ‘a’.
+        check("pаypal", TokenKind::Ident);
+    }
+
+    #[test]
+    fn lex_identifier_starting_with_digit() {
+        check_tokens("1st", &[(TokenKind::Number, "1"), (TokenKind::Ident, "st")]);
+    }
+
+    #[test]
+    fn lex_confusable_minus_sign() {
+        // U+2212 MINUS SIGN isn’t an operator, nor can it appear in an identifier.
+        check_tokens(
+            "a\u{2212}b",
+            &[
+                (TokenKind::Ident, "a"),
+                (TokenKind::Error, "\u{2212}"),
+                (TokenKind::Ident, "b"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_confusable_greek_question_mark() {
+        // U+037E GREEK QUESTION MARK looks just like a semicolon.
+        check_tokens(
+            "a\u{37e}",
+            &[(TokenKind::Ident, "a"), (TokenKind::Error, "\u{37e}")],
+        );
+    }
+
     #[test]
     fn lex_number() {
         check("123456", TokenKind::Number);
     }
 
+    #[test]
+    fn lex_string() {
+        check(r#""hello, world""#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_empty_string() {
+        check(r#""""#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_string_with_escapes() {
+        check(r#""a\"b\\c\nd\u{1F600}""#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_multiline_string() {
+        check("\"foo\nbar\"", TokenKind::String);
+    }
+
+    #[test]
+    fn lex_unterminated_string() {
+        check(r#""foo"#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_unterminated_string_ending_in_escaped_quote() {
+        check(r#""foo\""#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_unterminated_string_ending_in_backslash() {
+        check(r#""foo\"#, TokenKind::String);
+    }
+
+    #[test]
+    fn lex_number_with_digit_separators() {
+        check("1_000_000", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_hexadecimal_number() {
+        check("0xFF_ff", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_octal_number() {
+        check("0o755", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_binary_number() {
+        check("0b1010", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_number_with_radix_prefix_and_no_digits() {
+        check("0x", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_number_with_radix_prefix_and_invalid_digits() {
+        check("0b102", TokenKind::Number);
+    }
+
+    #[test]
+    fn lex_float() {
+        check("3.14", TokenKind::Float);
+    }
+
+    #[test]
+    fn lex_float_with_exponent() {
+        check("2.5e3", TokenKind::Float);
+    }
+
+    #[test]
+    fn lex_float_with_negative_exponent_and_no_fraction() {
+        check("1e-9", TokenKind::Float);
+    }
+
+    #[test]
+    fn lex_float_with_separators() {
+        check("1_000.000_1E+1_0", TokenKind::Float);
+    }
+
+    #[test]
+    fn lex_float_with_empty_exponent() {
+        check("1.5e+", TokenKind::Float);
+    }
+
     #[test]
     fn lex_plus() {
         check("+", TokenKind::Plus);
@@ -159,6 +590,150 @@ mod tests {
         check("=", TokenKind::Equals);
     }
 
+    #[test]
+    fn lex_plus_equals() {
+        check("+=", TokenKind::PlusEquals);
+    }
+
+    #[test]
+    fn lex_minus_equals() {
+        check("-=", TokenKind::MinusEquals);
+    }
+
+    #[test]
+    fn lex_star_equals() {
+        check("*=", TokenKind::StarEquals);
+    }
+
+    #[test]
+    fn lex_slash_equals() {
+        check("/=", TokenKind::SlashEquals);
+    }
+
+    #[test]
+    fn lex_equals_equals() {
+        check("==", TokenKind::EqualsEquals);
+    }
+
+    #[test]
+    fn lex_bang_equals() {
+        check("!=", TokenKind::BangEquals);
+    }
+
+    #[test]
+    fn lex_less() {
+        check("<", TokenKind::Less);
+    }
+
+    #[test]
+    fn lex_less_equals() {
+        check("<=", TokenKind::LessEquals);
+    }
+
+    #[test]
+    fn lex_greater() {
+        check(">", TokenKind::Greater);
+    }
+
+    #[test]
+    fn lex_greater_equals() {
+        check(">=", TokenKind::GreaterEquals);
+    }
+
+    #[test]
+    fn lex_and_and() {
+        check("&&", TokenKind::AndAnd);
+    }
+
+    #[test]
+    fn lex_or_or() {
+        check("||", TokenKind::OrOr);
+    }
+
+    #[test]
+    fn lex_pipe() {
+        check("|", TokenKind::Pipe);
+    }
+
+    #[test]
+    fn lex_closure_params() {
+        check_tokens(
+            "|x, y|",
+            &[
+                (TokenKind::Pipe, "|"),
+                (TokenKind::Ident, "x"),
+                (TokenKind::Comma, ","),
+                (TokenKind::Whitespace, " "),
+                (TokenKind::Ident, "y"),
+                (TokenKind::Pipe, "|"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_bang() {
+        check("!", TokenKind::Bang);
+    }
+
+    #[test]
+    fn lex_comma() {
+        check(",", TokenKind::Comma);
+    }
+
+    #[test]
+    fn lex_dot() {
+        check(".", TokenKind::Dot);
+    }
+
+    #[test]
+    fn lex_field_access() {
+        check_tokens(
+            "p.x",
+            &[
+                (TokenKind::Ident, "p"),
+                (TokenKind::Dot, "."),
+                (TokenKind::Ident, "x"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_colon() {
+        check(":", TokenKind::Colon);
+    }
+
+    #[test]
+    fn lex_colon_colon() {
+        check("::", TokenKind::ColonColon);
+    }
+
+    #[test]
+    fn lex_path() {
+        check_tokens(
+            "a::b",
+            &[
+                (TokenKind::Ident, "a"),
+                (TokenKind::ColonColon, "::"),
+                (TokenKind::Ident, "b"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_arrow() {
+        check("->", TokenKind::Arrow);
+    }
+
+    #[test]
+    fn lex_fat_arrow() {
+        check("=>", TokenKind::FatArrow);
+    }
+
+    #[test]
+    fn lex_semicolon() {
+        check(";", TokenKind::Semicolon);
+    }
+
     #[test]
     fn lex_left_parenthesis() {
         check("(", TokenKind::LParen);
@@ -179,11 +754,74 @@ mod tests {
         check("}", TokenKind::RBrace);
     }
 
+    #[test]
+    fn lex_left_bracket() {
+        check("[", TokenKind::LBracket);
+    }
+
+    #[test]
+    fn lex_right_bracket() {
+        check("]", TokenKind::RBracket);
+    }
+
     #[test]
     fn lex_comment() {
         check("# foo", TokenKind::Comment);
     }
 
+    #[test]
+    fn lex_empty_comment() {
+        check("#", TokenKind::Comment);
+    }
+
+    #[test]
+    fn lex_comment_followed_by_newline() {
+        check_tokens(
+            "# foo\nbar",
+            &[
+                (TokenKind::Comment, "# foo"),
+                (TokenKind::Whitespace, "\n"),
+                (TokenKind::Ident, "bar"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_block_comment() {
+        check("#[ foo\nbar ]#", TokenKind::Comment);
+    }
+
+    #[test]
+    fn lex_nested_block_comment() {
+        check("#[ a #[ b ]# c ]#", TokenKind::Comment);
+    }
+
+    #[test]
+    fn lex_code_after_block_comment() {
+        check_tokens(
+            "#[ a #[ b ]# ]#x",
+            &[
+                (TokenKind::Comment, "#[ a #[ b ]# ]#"),
+                (TokenKind::Ident, "x"),
+            ],
+        );
+    }
+
+    #[test]
+    fn lex_unterminated_block_comment() {
+        check("#[ a #[ b ]#\nc", TokenKind::Comment);
+    }
+
+    #[test]
+    fn lex_doc_comment() {
+        check("## Adds two numbers.", TokenKind::DocComment);
+    }
+
+    #[test]
+    fn lex_empty_doc_comment() {
+        check("##", TokenKind::DocComment);
+    }
+
     #[test]
     fn lex_error() {
         check("$", TokenKind::Error);
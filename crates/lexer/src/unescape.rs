@@ -0,0 +1,246 @@
+use std::fmt;
+use text_size::{TextRange, TextSize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeError {
+    /// A backslash at the very end of the text with nothing left to escape.
+    LoneBackslash,
+    /// A backslash followed by a character that doesn’t form a known escape, e.g. `\q`.
+    UnknownEscape,
+    /// A `\u` that isn’t followed by `{`.
+    NoBraceInUnicodeEscape,
+    /// A `\u{` that never reaches its closing `}`.
+    UnclosedUnicodeEscape,
+    /// A `\u{}` with no hex digits.
+    EmptyUnicodeEscape,
+    /// A character inside `\u{...}` that isn’t a hex digit.
+    InvalidCharInUnicodeEscape,
+    /// A `\u{...}` with more than six hex digits.
+    OverlongUnicodeEscape,
+    /// A `\u{...}` naming a surrogate or a value above `10FFFF`.
+    InvalidUnicodeCodePoint,
+}
+
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::LoneBackslash => "backslash has nothing to escape",
+            Self::UnknownEscape => "unknown character escape",
+            Self::NoBraceInUnicodeEscape => "unicode escape must be written as ‘\\u{...}’",
+            Self::UnclosedUnicodeEscape => "unicode escape is missing its closing ‘}’",
+            Self::EmptyUnicodeEscape => "unicode escape must have at least one hex digit",
+            Self::InvalidCharInUnicodeEscape => "invalid character in unicode escape",
+            Self::OverlongUnicodeEscape => "unicode escape must have at most six hex digits",
+            Self::InvalidUnicodeCodePoint => "unicode escape is not a valid unicode scalar value",
+        })
+    }
+}
+
+/// Strips the quotes off a string literal’s text, returning `None` if the closing quote is
+/// missing.
+pub fn string_contents(text: &str) -> Option<&str> {
+    let contents = text.strip_prefix('"')?;
+
+    // A lone `"` is an unterminated string, not an empty one.
+    let contents = contents.strip_suffix('"')?;
+
+    // An escaped quote at the end doesn’t terminate the string either, e.g. `"foo\"`.
+    let num_trailing_backslashes = contents.len() - contents.trim_end_matches('\\').len();
+    (num_trailing_backslashes % 2 == 0).then_some(contents)
+}
+
+/// Unescapes the contents of a string literal, i.e. the text between its quotes.
+///
+/// Every malformed escape is passed to `on_error` along with its range relative to the start of
+/// `text`; the returned string skips over those escapes.
+pub fn unescape(text: &str, mut on_error: impl FnMut(EscapeError, TextRange)) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        let range = |end: usize| {
+            TextRange::new(
+                TextSize::try_from(start).unwrap(),
+                TextSize::try_from(end).unwrap(),
+            )
+        };
+
+        let Some((idx, escaped)) = chars.next() else {
+            on_error(EscapeError::LoneBackslash, range(text.len()));
+            break;
+        };
+
+        let mut end = idx + escaped.len_utf8();
+
+        let c = match escaped {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            '"' => Ok('"'),
+            '\\' => Ok('\\'),
+            'u' => unescape_unicode(&mut chars, &mut end),
+            _ => Err(EscapeError::UnknownEscape),
+        };
+
+        match c {
+            Ok(c) => unescaped.push(c),
+            Err(error) => on_error(error, range(end)),
+        }
+    }
+
+    unescaped
+}
+
+type CharIndices<'a> = std::iter::Peekable<std::str::CharIndices<'a>>;
+
+/// Unescapes the `{...}` part of a `\u{...}` escape. `end` starts just past the `u` and is advanced
+/// past everything the escape consumed.
+fn unescape_unicode(chars: &mut CharIndices, end: &mut usize) -> Result<char, EscapeError> {
+    match chars.next_if(|(_, c)| *c == '{') {
+        Some((idx, _)) => *end = idx + 1,
+        None => return Err(EscapeError::NoBraceInUnicodeEscape),
+    }
+
+    let mut value: u32 = 0;
+    let mut num_digits = 0;
+    let mut has_invalid_char = false;
+
+    loop {
+        let Some((idx, c)) = chars.next() else {
+            return Err(EscapeError::UnclosedUnicodeEscape);
+        };
+
+        *end = idx + c.len_utf8();
+
+        match c {
+            '}' => break,
+            _ => match c.to_digit(16) {
+                Some(digit) => {
+                    num_digits += 1;
+                    value = value.saturating_mul(16).saturating_add(digit);
+                }
+                None => has_invalid_char = true,
+            },
+        }
+    }
+
+    if has_invalid_char {
+        return Err(EscapeError::InvalidCharInUnicodeEscape);
+    }
+
+    match num_digits {
+        0 => Err(EscapeError::EmptyUnicodeEscape),
+        7.. => Err(EscapeError::OverlongUnicodeEscape),
+        _ => char::from_u32(value).ok_or(EscapeError::InvalidUnicodeCodePoint),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Range as StdRange;
+
+    fn check(input: &str, expected: &str, expected_errors: &[(EscapeError, StdRange<u32>)]) {
+        let mut errors = Vec::new();
+        let unescaped = unescape(input, |error, range| errors.push((error, range)));
+
+        let expected_errors: Vec<_> = expected_errors
+            .iter()
+            .map(|(error, range)| (*error, TextRange::new(range.start.into(), range.end.into())))
+            .collect();
+
+        assert_eq!(unescaped, expected);
+        assert_eq!(errors, expected_errors);
+    }
+
+    #[test]
+    fn unescape_plain_text() {
+        check("hello", "hello", &[]);
+    }
+
+    #[test]
+    fn unescape_simple_escapes() {
+        check(r#"a\nb\tc\"d\\e"#, "a\nb\tc\"d\\e", &[]);
+    }
+
+    #[test]
+    fn unescape_unicode_escape() {
+        check(r"caf\u{e9} \u{1F600}", "café 😀", &[]);
+    }
+
+    #[test]
+    fn unescape_unknown_escape() {
+        check(r"a\qb", "ab", &[(EscapeError::UnknownEscape, 1..3)]);
+    }
+
+    #[test]
+    fn unescape_lone_backslash() {
+        check(r"abc\", "abc", &[(EscapeError::LoneBackslash, 3..4)]);
+    }
+
+    #[test]
+    fn unescape_unicode_escape_without_brace() {
+        check(
+            r"\u0041",
+            "0041",
+            &[(EscapeError::NoBraceInUnicodeEscape, 0..2)],
+        );
+    }
+
+    #[test]
+    fn unescape_unclosed_unicode_escape() {
+        check(
+            r"x\u{41",
+            "x",
+            &[(EscapeError::UnclosedUnicodeEscape, 1..6)],
+        );
+    }
+
+    #[test]
+    fn unescape_empty_unicode_escape() {
+        check(r"\u{}", "", &[(EscapeError::EmptyUnicodeEscape, 0..4)]);
+    }
+
+    #[test]
+    fn unescape_unicode_escape_with_invalid_char() {
+        check(
+            r"\u{4g}!",
+            "!",
+            &[(EscapeError::InvalidCharInUnicodeEscape, 0..6)],
+        );
+    }
+
+    #[test]
+    fn unescape_overlong_unicode_escape() {
+        check(
+            r"\u{0000041}",
+            "",
+            &[(EscapeError::OverlongUnicodeEscape, 0..11)],
+        );
+    }
+
+    #[test]
+    fn unescape_surrogate_unicode_escape() {
+        check(
+            r"\u{D800}",
+            "",
+            &[(EscapeError::InvalidUnicodeCodePoint, 0..8)],
+        );
+    }
+
+    #[test]
+    fn unescape_multiple_errors() {
+        check(
+            r"\a-\u{}",
+            "-",
+            &[
+                (EscapeError::UnknownEscape, 0..2),
+                (EscapeError::EmptyUnicodeEscape, 3..7),
+            ],
+        );
+    }
+}
@@ -1,19 +1,89 @@
 mod expr;
+mod pat;
 mod stmt;
+mod ty;
+
+pub(crate) use expr::EXPR_FIRST;
 
 use crate::parser::Parser;
 use crate::parser::marker::CompletedMarker;
 use lexer::TokenKind;
 use syntax::SyntaxKind;
 
-pub(crate) fn root(p: &mut Parser) -> CompletedMarker {
+pub(crate) fn root(p: &mut Parser) {
     let m = p.start();
 
     while !p.at_end() {
-        stmt::stmt(p);
+        // Empty statements are allowed, e.g. `;;`.
+        if p.at(TokenKind::Semicolon) {
+            p.bump();
+            continue;
+        }
+
+        stmt::terminated_stmt(p);
+    }
+
+    m.complete(p, SyntaxKind::Root);
+}
+
+pub(crate) fn expr_fragment(p: &mut Parser) {
+    fragment(p, expr::expr);
+}
+
+pub(crate) fn stmt_fragment(p: &mut Parser) {
+    fragment(p, stmt::stmt);
+}
+
+pub(crate) fn ty_fragment(p: &mut Parser) {
+    fragment(p, ty::ty);
+}
+
+/// Parses something that has to make up the whole input on its own, so that its node can be the
+/// root of the tree. If it can’t be, e.g. because something trails it, everything is put in an
+/// error node instead.
+fn fragment(p: &mut Parser, parse: fn(&mut Parser) -> Option<CompletedMarker>) {
+    let m = p.start();
+
+    if parse(p).is_some() && p.at_end() {
+        m.abandon();
+        return;
+    }
+
+    p.expect_end();
+
+    m.complete(p, SyntaxKind::Error);
+}
+
+/// Parses `item`s separated by commas, with an optional trailing comma, followed by `close`, e.g.
+/// the `a, b)` of `f(a, b)`. The opening bracket has to have been eaten already. Returns whether
+/// there were any commas, which is what makes e.g. `(a,)` a tuple.
+fn delimited(
+    p: &mut Parser,
+    close: TokenKind,
+    item: fn(&mut Parser) -> Option<CompletedMarker>,
+) -> bool {
+    let mut has_comma = false;
+
+    p.push_recovery_set(&[TokenKind::Comma, close]);
+
+    while !p.at(close) && !p.at_end() {
+        // Error recovery stopped at a token an enclosing construct is waiting for, e.g. the `}` in
+        // `{ f(a + }`, so we leave it to that construct.
+        if item(p).is_none() && p.at_recovery_set() && !p.at(TokenKind::Comma) {
+            break;
+        }
+
+        if !p.at(close) {
+            has_comma |= p.at(TokenKind::Comma);
+            p.expect(TokenKind::Comma);
+        }
     }
 
-    m.complete(p, SyntaxKind::Root)
+    p.pop_recovery_set();
+
+    p.expect(close);
+
+    has_comma
 }
 
 #[cfg(test)]
@@ -41,4 +111,303 @@ Root@0..11
     Ident@10..11 "a""#]],
         );
     }
+
+    #[test]
+    fn parse_statements_separated_by_semicolons() {
+        check(
+            "let a = 1; let b = 2; a",
+            expect![[r#"
+Root@0..23
+  VariableDef@0..9
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    Literal@8..9
+      Number@8..9 "1"
+  Semicolon@9..10 ";"
+  Whitespace@10..11 " "
+  VariableDef@11..20
+    LetKw@11..14 "let"
+    Whitespace@14..15 " "
+    Ident@15..16 "b"
+    Whitespace@16..17 " "
+    Equals@17..18 "="
+    Whitespace@18..19 " "
+    Literal@19..20
+      Number@19..20 "2"
+  Semicolon@20..21 ";"
+  Whitespace@21..22 " "
+  VariableRef@22..23
+    Ident@22..23 "a""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_statements() {
+        check(
+            ";;1;",
+            expect![[r#"
+Root@0..4
+  Semicolon@0..1 ";"
+  Semicolon@1..2 ";"
+  Literal@2..3
+    Number@2..3 "1"
+  Semicolon@3..4 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_statements_on_same_line() {
+        check(
+            "1 2 3",
+            expect![[r#"
+Root@0..5
+  Literal@0..2
+    Number@0..1 "1"
+    Whitespace@1..2 " "
+  Literal@2..4
+    Number@2..3 "2"
+    Whitespace@3..4 " "
+  Literal@4..5
+    Number@4..5 "3"
+error at 2..3: expected newline or ‘;’, but found number
+error at 4..5: expected newline or ‘;’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_variable_definitions_on_same_line() {
+        check(
+            "let a = 1 let b = 2",
+            expect![[r#"
+Root@0..19
+  VariableDef@0..10
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    Literal@8..10
+      Number@8..9 "1"
+      Whitespace@9..10 " "
+  VariableDef@10..19
+    LetKw@10..13 "let"
+    Whitespace@13..14 " "
+    Ident@14..15 "b"
+    Whitespace@15..16 " "
+    Equals@16..17 "="
+    Whitespace@17..18 " "
+    Literal@18..19
+      Number@18..19 "2"
+error at 10..13: expected newline or ‘;’, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_statement_after_block_on_same_line() {
+        check(
+            "if a { b } c",
+            expect![[r#"
+Root@0..12
+  IfExpr@0..11
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..11
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      VariableRef@7..9
+        Ident@7..8 "b"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}"
+      Whitespace@10..11 " "
+  VariableRef@11..12
+    Ident@11..12 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_statement_after_definition_on_same_line() {
+        check(
+            "fn f() {} struct A {} enum B {} c",
+            expect![[r#"
+Root@0..33
+  FnDef@0..10
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..10
+      LBrace@7..8 "{"
+      RBrace@8..9 "}"
+      Whitespace@9..10 " "
+  StructDef@10..22
+    StructKw@10..16 "struct"
+    Whitespace@16..17 " "
+    Ident@17..18 "A"
+    Whitespace@18..19 " "
+    FieldDefList@19..22
+      LBrace@19..20 "{"
+      RBrace@20..21 "}"
+      Whitespace@21..22 " "
+  EnumDef@22..32
+    EnumKw@22..26 "enum"
+    Whitespace@26..27 " "
+    Ident@27..28 "B"
+    Whitespace@28..29 " "
+    VariantList@29..32
+      LBrace@29..30 "{"
+      RBrace@30..31 "}"
+      Whitespace@31..32 " "
+  VariableRef@32..33
+    Ident@32..33 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_statement_after_block_value_on_same_line() {
+        check(
+            "let a = { 1 } 2",
+            expect![[r#"
+Root@0..15
+  VariableDef@0..14
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    BlockExpr@8..14
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      Literal@10..12
+        Number@10..11 "1"
+        Whitespace@11..12 " "
+      RBrace@12..13 "}"
+      Whitespace@13..14 " "
+  Literal@14..15
+    Number@14..15 "2"
+error at 14..15: expected newline or ‘;’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_statement_after_record_expr_on_same_line() {
+        check(
+            "let a = P { x: 1 } 2",
+            expect![[r#"
+Root@0..20
+  VariableDef@0..19
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    RecordExpr@8..19
+      Ident@8..9 "P"
+      Whitespace@9..10 " "
+      RecordFieldList@10..19
+        LBrace@10..11 "{"
+        Whitespace@11..12 " "
+        RecordField@12..17
+          Ident@12..13 "x"
+          Colon@13..14 ":"
+          Whitespace@14..15 " "
+          Literal@15..17
+            Number@15..16 "1"
+            Whitespace@16..17 " "
+        RBrace@17..18 "}"
+        Whitespace@18..19 " "
+  Literal@19..20
+    Number@19..20 "2"
+error at 19..20: expected newline or ‘;’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_infix_expression_continued_on_next_line() {
+        check(
+            "a\n  + b",
+            expect![[r#"
+Root@0..7
+  InfixExpr@0..7
+    VariableRef@0..4
+      Ident@0..1 "a"
+      Whitespace@1..4 "\n  "
+    Plus@4..5 "+"
+    Whitespace@5..6 " "
+    VariableRef@6..7
+      Ident@6..7 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_paren_expr_on_line_after_variable_ref() {
+        check(
+            "a\n(b)",
+            expect![[r#"
+Root@0..5
+  VariableRef@0..2
+    Ident@0..1 "a"
+    Whitespace@1..2 "\n"
+  ParenExpr@2..5
+    LParen@2..3 "("
+    VariableRef@3..4
+      Ident@3..4 "b"
+    RParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_split_over_lines_inside_parens() {
+        check(
+            "(a\n(b))",
+            expect![[r#"
+Root@0..7
+  ParenExpr@0..7
+    LParen@0..1 "("
+    CallExpr@1..6
+      VariableRef@1..3
+        Ident@1..2 "a"
+        Whitespace@2..3 "\n"
+      ArgList@3..6
+        LParen@3..4 "("
+        VariableRef@4..5
+          Ident@4..5 "b"
+        RParen@5..6 ")"
+    RParen@6..7 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_statement_after_unfinished_statement_on_same_line() {
+        check(
+            "let a = ) b",
+            expect![[r#"
+Root@0..11
+  VariableDef@0..10
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    Error@8..10
+      RParen@8..9 ")"
+      Whitespace@9..10 " "
+  VariableRef@10..11
+    Ident@10..11 "b"
+error at 8..9: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘)’"#]],
+        );
+    }
 }
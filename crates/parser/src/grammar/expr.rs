@@ -1,11 +1,46 @@
 use super::*;
 
+/// The tokens an expression can start with.
+pub(crate) const EXPR_FIRST: &[TokenKind] = &[
+    TokenKind::Number,
+    TokenKind::Float,
+    TokenKind::String,
+    TokenKind::TrueKw,
+    TokenKind::FalseKw,
+    TokenKind::Ident,
+    TokenKind::Minus,
+    TokenKind::Bang,
+    TokenKind::LParen,
+    TokenKind::LBrace,
+    TokenKind::LBracket,
+    TokenKind::IfKw,
+    TokenKind::MatchKw,
+    TokenKind::WhileKw,
+    TokenKind::LoopKw,
+    TokenKind::BreakKw,
+    TokenKind::ContinueKw,
+    TokenKind::Pipe,
+    TokenKind::OrOr,
+    TokenKind::FnKw,
+];
+
 pub(super) fn expr(p: &mut Parser) -> Option<CompletedMarker> {
     expr_binding_power(p, 0)
 }
 
 fn expr_binding_power(p: &mut Parser, minimum_binding_power: u8) -> Option<CompletedMarker> {
-    let mut lhs = lhs(p)?;
+    if !p.enter() {
+        return None;
+    }
+
+    let Some(mut lhs) = lhs(p) else {
+        p.exit();
+        return None;
+    };
+
+    // Every operator nests the expression before it one level deeper, e.g. `a + b + c` is
+    // `(a + b) + c`, so operators count towards the nesting depth too.
+    let mut num_operators = 0;
 
     loop {
         let op = if p.at(TokenKind::Plus) {
@@ -16,6 +51,62 @@ fn expr_binding_power(p: &mut Parser, minimum_binding_power: u8) -> Option<Compl
             BinaryOp::Mul
         } else if p.at(TokenKind::Slash) {
             BinaryOp::Div
+        } else if p.at(TokenKind::EqualsEquals) {
+            BinaryOp::Eq
+        } else if p.at(TokenKind::BangEquals) {
+            BinaryOp::NotEq
+        } else if p.at(TokenKind::Less) {
+            BinaryOp::Lt
+        } else if p.at(TokenKind::LessEquals) {
+            BinaryOp::LtEq
+        } else if p.at(TokenKind::Greater) {
+            BinaryOp::Gt
+        } else if p.at(TokenKind::GreaterEquals) {
+            BinaryOp::GtEq
+        } else if p.at(TokenKind::AndAnd) {
+            BinaryOp::And
+        } else if p.at(TokenKind::OrOr) {
+            BinaryOp::Or
+        } else if p.at(TokenKind::Equals) {
+            BinaryOp::Assign
+        } else if p.at(TokenKind::PlusEquals) {
+            BinaryOp::AddAssign
+        } else if p.at(TokenKind::MinusEquals) {
+            BinaryOp::SubAssign
+        } else if p.at(TokenKind::StarEquals) {
+            BinaryOp::MulAssign
+        } else if p.at(TokenKind::SlashEquals) {
+            BinaryOp::DivAssign
+        } else if let Some(op) = postfix_op(p) {
+            let (left_binding_power, ()) = op.binding_power();
+            if left_binding_power < minimum_binding_power {
+                break;
+            }
+
+            if !p.enter() {
+                break;
+            }
+
+            num_operators += 1;
+
+            let m = lhs.precede(p);
+
+            lhs = match op {
+                PostfixOp::Call => {
+                    arg_list(p);
+                    m.complete(p, SyntaxKind::CallExpr)
+                }
+                PostfixOp::Index => {
+                    index(p);
+                    m.complete(p, SyntaxKind::IndexExpr)
+                }
+                PostfixOp::Field => {
+                    field(p);
+                    m.complete(p, SyntaxKind::FieldExpr)
+                }
+            };
+
+            continue;
         } else {
             // We’re not at an operator; we don’t know what to do next, so we return and let the
             // caller decide.
@@ -27,30 +118,80 @@ fn expr_binding_power(p: &mut Parser, minimum_binding_power: u8) -> Option<Compl
             break;
         }
 
+        if !p.enter() {
+            break;
+        }
+
+        num_operators += 1;
+
         // Eat the operator’s token.
         p.bump();
 
         let m = lhs.precede(p);
         let parsed_rhs = expr_binding_power(p, right_binding_power).is_some();
-        lhs = m.complete(p, SyntaxKind::InfixExpr);
+        lhs = m.complete(p, op.node_kind());
 
         if !parsed_rhs {
             break;
         }
     }
 
+    for _ in 0..=num_operators {
+        p.exit();
+    }
+
     Some(lhs)
 }
 
+/// Returns the postfix operator starting at the current token, if there is one. Like a binary
+/// operator, a `.` at the start of a line continues the expression before it, but a `(` or `[`
+/// there starts a new statement instead of applying to what came before it, e.g. in `a\n(b)`.
+fn postfix_op(p: &mut Parser) -> Option<PostfixOp> {
+    if (p.at(TokenKind::LParen) || p.at(TokenKind::LBracket)) && !p.at_line_break() {
+        if p.at(TokenKind::LParen) {
+            Some(PostfixOp::Call)
+        } else {
+            Some(PostfixOp::Index)
+        }
+    } else if p.at(TokenKind::Dot) {
+        Some(PostfixOp::Field)
+    } else {
+        None
+    }
+}
+
 fn lhs(p: &mut Parser) -> Option<CompletedMarker> {
-    let cm = if p.at(TokenKind::Number) {
+    let cm = if p.at(TokenKind::Number)
+        || p.at(TokenKind::Float)
+        || p.at(TokenKind::String)
+        || p.at(TokenKind::TrueKw)
+        || p.at(TokenKind::FalseKw)
+    {
         literal(p)
     } else if p.at(TokenKind::Ident) {
-        variable_ref(p)
-    } else if p.at(TokenKind::Minus) {
+        variable_ref_or_record_expr(p)
+    } else if p.at(TokenKind::Minus) || p.at(TokenKind::Bang) {
         prefix_expr(p)
     } else if p.at(TokenKind::LParen) {
         paren_expr(p)
+    } else if p.at(TokenKind::LBrace) {
+        block_expr(p)
+    } else if p.at(TokenKind::LBracket) {
+        array_expr(p)
+    } else if p.at(TokenKind::IfKw) {
+        if_expr(p)
+    } else if p.at(TokenKind::MatchKw) {
+        match_expr(p)
+    } else if p.at(TokenKind::WhileKw) {
+        while_expr(p)
+    } else if p.at(TokenKind::LoopKw) {
+        loop_expr(p)
+    } else if p.at(TokenKind::BreakKw) {
+        break_expr(p)
+    } else if p.at(TokenKind::ContinueKw) {
+        continue_expr(p)
+    } else if p.at(TokenKind::Pipe) || p.at(TokenKind::OrOr) || p.at(TokenKind::FnKw) {
+        closure_expr(p)
     } else {
         p.error();
         return None;
@@ -65,14 +206,52 @@ enum BinaryOp {
     Sub,
     Mul,
     Div,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    And,
+    Or,
+    Assign,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
 }
 
 impl BinaryOp {
+    /// Every binary operator is left-associative except for assignments, which are
+    /// right-associative so that `a = b = c` assigns `c` to `b` and then to `a`. From loosest to
+    /// tightest, the precedence levels are assignment, ‘||’, ‘&&’, equality, comparison, addition
+    /// and multiplication.
     #[inline]
     fn binding_power(&self) -> (u8, u8) {
         match self {
-            Self::Add | Self::Sub => (1, 2),
-            Self::Mul | Self::Div => (3, 4),
+            Self::Assign
+            | Self::AddAssign
+            | Self::SubAssign
+            | Self::MulAssign
+            | Self::DivAssign => (2, 1),
+            Self::Or => (3, 4),
+            Self::And => (5, 6),
+            Self::Eq | Self::NotEq => (7, 8),
+            Self::Lt | Self::LtEq | Self::Gt | Self::GtEq => (9, 10),
+            Self::Add | Self::Sub => (11, 12),
+            Self::Mul | Self::Div => (13, 14),
+        }
+    }
+
+    #[inline]
+    fn node_kind(&self) -> SyntaxKind {
+        match self {
+            Self::Assign
+            | Self::AddAssign
+            | Self::SubAssign
+            | Self::MulAssign
+            | Self::DivAssign => SyntaxKind::AssignExpr,
+            _ => SyntaxKind::InfixExpr,
         }
     }
 }
@@ -80,39 +259,136 @@ impl BinaryOp {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum UnaryOp {
     Neg,
+    Not,
 }
 
 impl UnaryOp {
     #[inline]
     fn binding_power(&self) -> ((), u8) {
         match self {
-            Self::Neg => ((), 5),
+            Self::Neg | Self::Not => ((), 15),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum PostfixOp {
+    Call,
+    Index,
+    Field,
+}
+
+impl PostfixOp {
+    #[inline]
+    fn binding_power(&self) -> (u8, ()) {
+        match self {
+            Self::Call | Self::Index | Self::Field => (17, ()),
         }
     }
 }
 
-fn literal(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(TokenKind::Number));
+pub(super) fn literal(p: &mut Parser) -> CompletedMarker {
+    assert!(
+        p.at(TokenKind::Number)
+            || p.at(TokenKind::Float)
+            || p.at(TokenKind::String)
+            || p.at(TokenKind::TrueKw)
+            || p.at(TokenKind::FalseKw)
+    );
 
     let m = p.start();
     p.bump();
     m.complete(p, SyntaxKind::Literal)
 }
 
-fn variable_ref(p: &mut Parser) -> CompletedMarker {
+/// Parses a name, which is either a variable reference, the start of a path if it’s followed by
+/// `::`, e.g. `shapes::area`, or, if it’s followed by `{` on the same line, the start of a record
+/// expression, e.g. `Point { x: 1, y: 2 }`.
+fn variable_ref_or_record_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(TokenKind::Ident));
 
     let m = p.start();
+    let path_m = p.start();
     p.bump();
+
+    if p.at(TokenKind::ColonColon) {
+        path_segments(p);
+        path_m.complete(p, SyntaxKind::Path);
+        return m.complete(p, SyntaxKind::PathExpr);
+    }
+
+    path_m.abandon();
+
+    if p.records_allowed() && !p.at_line_break() && p.at(TokenKind::LBrace) {
+        record_field_list(p);
+        return m.complete(p, SyntaxKind::RecordExpr);
+    }
+
     m.complete(p, SyntaxKind::VariableRef)
 }
 
+/// Parses a `::`-separated path, e.g. `a::b::c`.
+pub(super) fn path(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::Ident));
+
+    let m = p.start();
+    p.bump();
+    path_segments(p);
+    m.complete(p, SyntaxKind::Path)
+}
+
+/// Parses the segments of a path after its first one, e.g. the `::b::c` in `a::b::c`.
+pub(super) fn path_segments(p: &mut Parser) {
+    while p.at(TokenKind::ColonColon) {
+        p.bump();
+        p.expect(TokenKind::Ident);
+    }
+}
+
+fn record_field_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBrace));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    let records_allowed = p.set_records_allowed(true);
+    delimited(p, TokenKind::RBrace, record_field_or_error);
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    m.complete(p, SyntaxKind::RecordFieldList)
+}
+
+fn record_field_or_error(p: &mut Parser) -> Option<CompletedMarker> {
+    if !p.at(TokenKind::Ident) {
+        p.error();
+        return None;
+    }
+
+    let m = p.start();
+    p.bump();
+
+    if p.at(TokenKind::Colon) {
+        p.bump();
+        expr(p);
+    } else {
+        p.error();
+    }
+
+    Some(m.complete(p, SyntaxKind::RecordField))
+}
+
 fn prefix_expr(p: &mut Parser) -> CompletedMarker {
-    assert!(p.at(TokenKind::Minus));
+    let op = if p.at(TokenKind::Minus) {
+        UnaryOp::Neg
+    } else {
+        assert!(p.at(TokenKind::Bang));
+        UnaryOp::Not
+    };
 
     let m = p.start();
 
-    let op = UnaryOp::Neg;
     let ((), right_binding_power) = op.binding_power();
 
     // Eat the operator’s token.
@@ -123,20 +399,371 @@ fn prefix_expr(p: &mut Parser) -> CompletedMarker {
     m.complete(p, SyntaxKind::PrefixExpr)
 }
 
+/// Parses either a parenthesized expression, e.g. `(a)`, or a tuple, e.g. `(a, b)`. A comma makes
+/// it a tuple, so a tuple with one field needs a trailing comma, e.g. `(a,)`; `()` is the empty
+/// tuple.
 fn paren_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(TokenKind::LParen));
 
     let m = p.start();
     p.bump();
-    expr_binding_power(p, 0);
-    p.expect(TokenKind::RParen);
 
-    m.complete(p, SyntaxKind::ParenExpr)
+    let is_empty = p.at(TokenKind::RParen);
+
+    let newlines_significant = p.set_newlines_significant(false);
+    let records_allowed = p.set_records_allowed(true);
+    let has_comma = delimited(p, TokenKind::RParen, expr);
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    let is_tuple = is_empty || has_comma;
+
+    let kind = if is_tuple {
+        SyntaxKind::TupleExpr
+    } else {
+        SyntaxKind::ParenExpr
+    };
+
+    m.complete(p, kind)
+}
+
+fn array_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBracket));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    let records_allowed = p.set_records_allowed(true);
+    delimited(p, TokenKind::RBracket, expr);
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    m.complete(p, SyntaxKind::ArrayExpr)
+}
+
+/// Parses the `.x` of a field access.
+fn field(p: &mut Parser) {
+    assert!(p.at(TokenKind::Dot));
+    p.bump();
+    p.expect(TokenKind::Ident);
+}
+
+/// Parses the `[i]` of an index expression.
+fn index(p: &mut Parser) {
+    assert!(p.at(TokenKind::LBracket));
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    let records_allowed = p.set_records_allowed(true);
+    p.push_recovery_set(&[TokenKind::RBracket]);
+    expr(p);
+    p.pop_recovery_set();
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    p.expect(TokenKind::RBracket);
+}
+
+fn block_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBrace));
+
+    let m = p.start();
+    p.bump();
+
+    // Line breaks end statements inside blocks, even if the block is inside parentheses.
+    let newlines_significant = p.set_newlines_significant(true);
+    let records_allowed = p.set_records_allowed(true);
+    p.push_recovery_set(&[TokenKind::RBrace, TokenKind::Semicolon]);
+
+    while !p.at(TokenKind::RBrace) && !p.at_end() {
+        // Empty statements are allowed, e.g. `{ ; }`.
+        if p.at(TokenKind::Semicolon) {
+            p.bump();
+            continue;
+        }
+
+        // Error recovery doesn’t eat tokens that an enclosing construct is waiting for, e.g. the
+        // `)` in `({ a )`, so we leave those to it.
+        if stmt::terminated_stmt(p).is_none() && p.at_recovery_set() && !p.at(TokenKind::Semicolon)
+        {
+            break;
+        }
+    }
+
+    p.pop_recovery_set();
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    p.expect(TokenKind::RBrace);
+
+    m.complete(p, SyntaxKind::BlockExpr)
+}
+
+/// Parses a block that has to come next, e.g. a loop’s body. If something else is in the way, it’s
+/// reported and skipped over so that the block can still be parsed.
+pub(super) fn block_or_error(p: &mut Parser) {
+    if !p.at(TokenKind::LBrace) {
+        p.error();
+    }
+
+    // Blocks can nest without going through an expression, e.g. through function definitions,
+    // so they count towards the nesting depth too.
+    if p.at(TokenKind::LBrace) && p.enter() {
+        block_expr(p);
+        p.exit();
+    }
+}
+
+fn if_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::IfKw));
+
+    let m = p.start();
+    p.bump();
+
+    expr_without_records(p);
+
+    block_or_error(p);
+
+    if p.at(TokenKind::ElseKw) {
+        p.bump();
+
+        if p.at(TokenKind::IfKw) {
+            // An ‘else if’ chain nests without going through an expression.
+            if p.enter() {
+                if_expr(p);
+                p.exit();
+            }
+        } else if p.at(TokenKind::LBrace) {
+            block_expr(p);
+        } else {
+            p.error();
+        }
+    }
+
+    m.complete(p, SyntaxKind::IfExpr)
+}
+
+fn while_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::WhileKw));
+
+    let m = p.start();
+    p.bump();
+
+    expr_without_records(p);
+
+    block_or_error(p);
+
+    m.complete(p, SyntaxKind::WhileExpr)
+}
+
+/// Parses the expression before the `{` of an ‘if’, ‘while’ or ‘match’, which can’t be a record
+/// expression unless it’s nested in something else, since the `{` starts the body instead.
+fn expr_without_records(p: &mut Parser) {
+    let records_allowed = p.set_records_allowed(false);
+    expr(p);
+    p.set_records_allowed(records_allowed);
+}
+
+fn match_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::MatchKw));
+
+    let m = p.start();
+    p.bump();
+
+    expr_without_records(p);
+
+    if p.at(TokenKind::LBrace) {
+        match_arm_list(p);
+    } else {
+        p.error();
+    }
+
+    m.complete(p, SyntaxKind::MatchExpr)
+}
+
+/// Parses the arms of a ‘match’. Like statements in a block, each arm has to be the last one on its
+/// line or be followed by a ‘,’, unless its body is block-like.
+fn match_arm_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBrace));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(true);
+    let records_allowed = p.set_records_allowed(true);
+    p.push_recovery_set(&[TokenKind::Comma, TokenKind::RBrace]);
+
+    while !p.at(TokenKind::RBrace) && !p.at_end() {
+        let num_errors = p.num_errors();
+        let mut body = None;
+
+        // Unlike in comma-separated lists, arms can also be separated by line breaks, so they
+        // can’t use `delimited`. We stop at a token an enclosing construct is waiting for, e.g. the
+        // `)` in `(match a { )`, but anything else starts an arm, even if it’s missing its pattern.
+        if pat::at_pat_start(p) || !p.at_recovery_set() {
+            body = match_arm(p);
+        } else {
+            p.error();
+
+            if !p.at(TokenKind::Comma) {
+                break;
+            }
+        }
+
+        if p.at(TokenKind::Comma) {
+            p.bump();
+        } else if !p.at(TokenKind::RBrace)
+            && !p.at_line_break()
+            && !body.is_some_and(|body| stmt::is_block_like(body.kind()))
+            && p.num_errors() == num_errors
+        {
+            p.error_missing_terminator(TokenKind::Comma);
+        }
+    }
+
+    p.pop_recovery_set();
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    p.expect(TokenKind::RBrace);
+
+    m.complete(p, SyntaxKind::MatchArmList)
+}
+
+/// Parses an arm, returning its body.
+fn match_arm(p: &mut Parser) -> Option<CompletedMarker> {
+    let m = p.start();
+
+    p.push_recovery_set(&[TokenKind::FatArrow]);
+
+    pat::pat(p);
+
+    if p.at(TokenKind::IfKw) {
+        match_guard(p);
+    }
+
+    p.expect(TokenKind::FatArrow);
+
+    p.pop_recovery_set();
+
+    let body = expr(p);
+
+    m.complete(p, SyntaxKind::MatchArm);
+
+    body
+}
+
+/// Parses the `if` and condition that an arm can have after its pattern.
+fn match_guard(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::IfKw));
+
+    let m = p.start();
+    p.bump();
+    expr(p);
+    m.complete(p, SyntaxKind::MatchGuard)
+}
+
+fn loop_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LoopKw));
+
+    let m = p.start();
+    p.bump();
+
+    block_or_error(p);
+
+    m.complete(p, SyntaxKind::LoopExpr)
+}
+
+fn break_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::BreakKw));
+
+    let m = p.start();
+    p.bump();
+
+    // The value is optional, so we only try to parse one if it looks like there is one.
+    if p.at_set(EXPR_FIRST) && !p.at_line_break() {
+        expr(p);
+    }
+
+    m.complete(p, SyntaxKind::BreakExpr)
+}
+
+fn continue_expr(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::ContinueKw));
+
+    let m = p.start();
+    p.bump();
+    m.complete(p, SyntaxKind::ContinueExpr)
+}
+
+/// Parses a closure, either `|x, y| x + y` or `fn(x, y) => x + y`. The body is any expression and
+/// extends as far as it can, so `|x| x + 1` returns `x + 1`; `||` starts a closure without
+/// parameters.
+fn closure_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+
+    if p.at(TokenKind::FnKw) {
+        p.bump();
+
+        // Stopping at `{` means that leaving out the `=>` before a block, e.g. `fn(x) { x }`, still
+        // parses the block as the body.
+        p.push_recovery_set(&[TokenKind::FatArrow, TokenKind::LBrace]);
+
+        if p.at(TokenKind::LParen) {
+            stmt::param_list(p);
+        } else {
+            p.error();
+        }
+
+        p.expect(TokenKind::FatArrow);
+
+        p.pop_recovery_set();
+    } else {
+        closure_param_list(p);
+    }
+
+    expr(p);
+
+    m.complete(p, SyntaxKind::ClosureExpr)
+}
+
+fn closure_param_list(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+
+    if p.at(TokenKind::OrOr) {
+        p.bump();
+        return m.complete(p, SyntaxKind::ParamList);
+    }
+
+    assert!(p.at(TokenKind::Pipe));
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    delimited(p, TokenKind::Pipe, stmt::param_or_error);
+    p.set_newlines_significant(newlines_significant);
+
+    m.complete(p, SyntaxKind::ParamList)
+}
+
+fn arg_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LParen));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    let records_allowed = p.set_records_allowed(true);
+    delimited(p, TokenKind::RParen, expr);
+    p.set_newlines_significant(newlines_significant);
+    p.set_records_allowed(records_allowed);
+
+    m.complete(p, SyntaxKind::ArgList)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::check;
+    use crate::{check, check_with_max_depth};
     use expect_test::expect;
 
     #[test]
@@ -151,42 +778,210 @@ Root@0..3
     }
 
     #[test]
-    fn parse_number_preceded_by_whitespace() {
+    fn parse_hexadecimal_number_with_separators() {
         check(
-            "   9876",
+            "0xdead_BEEF",
             expect![[r#"
-Root@0..7
-  Whitespace@0..3 "   "
-  Literal@3..7
-    Number@3..7 "9876""#]],
+Root@0..11
+  Literal@0..11
+    Number@0..11 "0xdead_BEEF""#]],
         );
     }
 
     #[test]
-    fn parse_number_followed_by_whitespace() {
+    fn parse_float() {
         check(
-            "999   ",
+            "1.5e-3",
             expect![[r#"
 Root@0..6
   Literal@0..6
-    Number@0..3 "999"
-    Whitespace@3..6 "   ""#]],
+    Float@0..6 "1.5e-3""#]],
         );
     }
 
     #[test]
-    fn parse_number_surrounded_by_whitespace() {
+    fn parse_float_in_binary_expr() {
         check(
-            " 123     ",
+            "2.0 * 3.25",
             expect![[r#"
-Root@0..9
-  Whitespace@0..1 " "
-  Literal@1..9
+Root@0..10
+  InfixExpr@0..10
+    Literal@0..4
+      Float@0..3 "2.0"
+      Whitespace@3..4 " "
+    Star@4..5 "*"
+    Whitespace@5..6 " "
+    Literal@6..10
+      Float@6..10 "3.25""#]],
+        );
+    }
+
+    #[test]
+    fn parse_number_preceded_by_whitespace() {
+        check(
+            "   9876",
+            expect![[r#"
+Root@0..7
+  Whitespace@0..3 "   "
+  Literal@3..7
+    Number@3..7 "9876""#]],
+        );
+    }
+
+    #[test]
+    fn parse_number_followed_by_whitespace() {
+        check(
+            "999   ",
+            expect![[r#"
+Root@0..6
+  Literal@0..6
+    Number@0..3 "999"
+    Whitespace@3..6 "   ""#]],
+        );
+    }
+
+    #[test]
+    fn parse_number_surrounded_by_whitespace() {
+        check(
+            " 123     ",
+            expect![[r#"
+Root@0..9
+  Whitespace@0..1 " "
+  Literal@1..9
     Number@1..4 "123"
     Whitespace@4..9 "     ""#]],
         );
     }
 
+    #[test]
+    fn parse_string() {
+        check(
+            r#""hello""#,
+            expect![[r#"
+Root@0..7
+  Literal@0..7
+    String@0..7 "\"hello\"""#]],
+        );
+    }
+
+    #[test]
+    fn parse_string_with_escapes() {
+        check(
+            r#""a\tb\u{41}""#,
+            expect![[r#"
+Root@0..12
+  Literal@0..12
+    String@0..12 "\"a\\tb\\u{41}\"""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unterminated_string() {
+        check(
+            r#"1 + "abc"#,
+            expect![[r#"
+Root@0..8
+  InfixExpr@0..8
+    Literal@0..2
+      Number@0..1 "1"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    Literal@4..8
+      String@4..8 "\"abc"
+error at 4..5: string literal is missing its closing ‘"’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_string_with_invalid_escapes() {
+        check(
+            r#""\q\u{}""#,
+            expect![[r#"
+Root@0..8
+  Literal@0..8
+    String@0..8 "\"\\q\\u{}\""
+error at 1..3: unknown character escape
+error at 3..7: unicode escape must have at least one hex digit"#]],
+        );
+    }
+
+    #[test]
+    fn parse_invalid_character() {
+        check(
+            "1 + $",
+            expect![[r#"
+Root@0..5
+  InfixExpr@0..5
+    Literal@0..2
+      Number@0..1 "1"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    Error@4..5
+      Error@4..5 "$"
+error at 4..5: invalid character ‘$’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_errors_in_source_order() {
+        check(
+            "\"\\q\" + @",
+            expect![[r#"
+Root@0..8
+  InfixExpr@0..8
+    Literal@0..5
+      String@0..4 "\"\\q\""
+      Whitespace@4..5 " "
+    Plus@5..6 "+"
+    Whitespace@6..7 " "
+    Error@7..8
+      Error@7..8 "@"
+error at 1..3: unknown character escape
+error at 7..8: invalid character ‘@’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_true() {
+        check(
+            "true",
+            expect![[r#"
+Root@0..4
+  Literal@0..4
+    TrueKw@0..4 "true""#]],
+        );
+    }
+
+    #[test]
+    fn parse_false() {
+        check(
+            "false",
+            expect![[r#"
+Root@0..5
+  Literal@0..5
+    FalseKw@0..5 "false""#]],
+        );
+    }
+
+    #[test]
+    fn parse_comparison_with_boolean() {
+        check(
+            "a == false",
+            expect![[r#"
+Root@0..10
+  InfixExpr@0..10
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    EqualsEquals@2..4 "=="
+    Whitespace@4..5 " "
+    Literal@5..10
+      FalseKw@5..10 "false""#]],
+        );
+    }
+
     #[test]
     fn parse_variable_ref() {
         check(
@@ -326,8 +1121,7 @@ Root@0..3
       Literal@1..2
         Number@1..2 "1"
       Plus@2..3 "+"
-error at 2..3: expected number, identifier, ‘-’ or ‘(’
-error at 2..3: expected ‘)’"#]],
+error at 2..3: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’"#]],
         );
     }
 
@@ -422,7 +1216,2993 @@ Root@0..4
     LParen@0..1 "("
     VariableRef@1..4
       Ident@1..4 "foo"
-error at 1..4: expected ‘+’, ‘-’, ‘*’, ‘/’ or ‘)’"#]],
+error at 1..4: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘)’ or ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_call() {
+        check(
+            "f(a, b + 1)",
+            expect![[r#"
+Root@0..11
+  CallExpr@0..11
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..11
+      LParen@1..2 "("
+      VariableRef@2..3
+        Ident@2..3 "a"
+      Comma@3..4 ","
+      Whitespace@4..5 " "
+      InfixExpr@5..10
+        VariableRef@5..7
+          Ident@5..6 "b"
+          Whitespace@6..7 " "
+        Plus@7..8 "+"
+        Whitespace@8..9 " "
+        Literal@9..10
+          Number@9..10 "1"
+      RParen@10..11 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_without_args() {
+        check(
+            "f()",
+            expect![[r#"
+Root@0..3
+  CallExpr@0..3
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..3
+      LParen@1..2 "("
+      RParen@2..3 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_with_trailing_comma() {
+        check(
+            "f(1,)",
+            expect![[r#"
+Root@0..5
+  CallExpr@0..5
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..5
+      LParen@1..2 "("
+      Literal@2..3
+        Number@2..3 "1"
+      Comma@3..4 ","
+      RParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_chained_calls() {
+        check(
+            "f(1)(2)",
+            expect![[r#"
+Root@0..7
+  CallExpr@0..7
+    CallExpr@0..4
+      VariableRef@0..1
+        Ident@0..1 "f"
+      ArgList@1..4
+        LParen@1..2 "("
+        Literal@2..3
+          Number@2..3 "1"
+        RParen@3..4 ")"
+    ArgList@4..7
+      LParen@4..5 "("
+      Literal@5..6
+        Number@5..6 "2"
+      RParen@6..7 ")""#]],
+        );
+    }
+
+    #[test]
+    fn call_has_higher_binding_power_than_binary_operators() {
+        check(
+            "1*f(2)+3",
+            expect![[r#"
+Root@0..8
+  InfixExpr@0..8
+    InfixExpr@0..6
+      Literal@0..1
+        Number@0..1 "1"
+      Star@1..2 "*"
+      CallExpr@2..6
+        VariableRef@2..3
+          Ident@2..3 "f"
+        ArgList@3..6
+          LParen@3..4 "("
+          Literal@4..5
+            Number@4..5 "2"
+          RParen@5..6 ")"
+    Plus@6..7 "+"
+    Literal@7..8
+      Number@7..8 "3""#]],
+        );
+    }
+
+    #[test]
+    fn call_has_higher_binding_power_than_negation() {
+        check(
+            "-f(1)",
+            expect![[r#"
+Root@0..5
+  PrefixExpr@0..5
+    Minus@0..1 "-"
+    CallExpr@1..5
+      VariableRef@1..2
+        Ident@1..2 "f"
+      ArgList@2..5
+        LParen@2..3 "("
+        Literal@3..4
+          Number@3..4 "1"
+        RParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_on_parenthesized_expression() {
+        check(
+            "(f)(1)",
+            expect![[r#"
+Root@0..6
+  CallExpr@0..6
+    ParenExpr@0..3
+      LParen@0..1 "("
+      VariableRef@1..2
+        Ident@1..2 "f"
+      RParen@2..3 ")"
+    ArgList@3..6
+      LParen@3..4 "("
+      Literal@4..5
+        Number@4..5 "1"
+      RParen@5..6 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_with_missing_right_parenthesis() {
+        check(
+            "f(a, b",
+            expect![[r#"
+Root@0..6
+  CallExpr@0..6
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..6
+      LParen@1..2 "("
+      VariableRef@2..3
+        Ident@2..3 "a"
+      Comma@3..4 ","
+      Whitespace@4..5 " "
+      VariableRef@5..6
+        Ident@5..6 "b"
+error at 5..6: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘)’ or ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_with_trailing_comma_and_missing_right_parenthesis() {
+        check(
+            "f(a,",
+            expect![[r#"
+Root@0..4
+  CallExpr@0..4
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..4
+      LParen@1..2 "("
+      VariableRef@2..3
+        Ident@2..3 "a"
+      Comma@3..4 ","
+error at 3..4: expected ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_with_missing_comma_between_arguments() {
+        check(
+            "f(a b)",
+            expect![[r#"
+Root@0..6
+  CallExpr@0..6
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..6
+      LParen@1..2 "("
+      VariableRef@2..4
+        Ident@2..3 "a"
+        Whitespace@3..4 " "
+      Error@4..5
+        Ident@4..5 "b"
+      RParen@5..6 ")"
+error at 4..5: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘)’ or ‘,’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_expr() {
+        check(
+            "{ let a = 1; a + 2 }",
+            expect![[r#"
+Root@0..20
+  BlockExpr@0..20
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    VariableDef@2..11
+      LetKw@2..5 "let"
+      Whitespace@5..6 " "
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+      Equals@8..9 "="
+      Whitespace@9..10 " "
+      Literal@10..11
+        Number@10..11 "1"
+    Semicolon@11..12 ";"
+    Whitespace@12..13 " "
+    InfixExpr@13..19
+      VariableRef@13..15
+        Ident@13..14 "a"
+        Whitespace@14..15 " "
+      Plus@15..16 "+"
+      Whitespace@16..17 " "
+      Literal@17..19
+        Number@17..18 "2"
+        Whitespace@18..19 " "
+    RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_block_expr() {
+        check(
+            "{}",
+            expect![[r#"
+Root@0..2
+  BlockExpr@0..2
+    LBrace@0..1 "{"
+    RBrace@1..2 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_expr_without_tail_expr() {
+        check(
+            "{ a; }",
+            expect![[r#"
+Root@0..6
+  BlockExpr@0..6
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    VariableRef@2..3
+      Ident@2..3 "a"
+    Semicolon@3..4 ";"
+    Whitespace@4..5 " "
+    RBrace@5..6 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_expr_with_newline_separated_statements() {
+        check(
+            "{\n  let a = 1\n  a\n}",
+            expect![[r#"
+Root@0..19
+  BlockExpr@0..19
+    LBrace@0..1 "{"
+    Whitespace@1..4 "\n  "
+    VariableDef@4..16
+      LetKw@4..7 "let"
+      Whitespace@7..8 " "
+      Ident@8..9 "a"
+      Whitespace@9..10 " "
+      Equals@10..11 "="
+      Whitespace@11..12 " "
+      Literal@12..16
+        Number@12..13 "1"
+        Whitespace@13..16 "\n  "
+    VariableRef@16..18
+      Ident@16..17 "a"
+      Whitespace@17..18 "\n"
+    RBrace@18..19 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_block_exprs() {
+        check(
+            "{ { 1 } }",
+            expect![[r#"
+Root@0..9
+  BlockExpr@0..9
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    BlockExpr@2..8
+      LBrace@2..3 "{"
+      Whitespace@3..4 " "
+      Literal@4..6
+        Number@4..5 "1"
+        Whitespace@5..6 " "
+      RBrace@6..7 "}"
+      Whitespace@7..8 " "
+    RBrace@8..9 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_expr_as_operand() {
+        check(
+            "1 + { 2 }",
+            expect![[r#"
+Root@0..9
+  InfixExpr@0..9
+    Literal@0..2
+      Number@0..1 "1"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    BlockExpr@4..9
+      LBrace@4..5 "{"
+      Whitespace@5..6 " "
+      Literal@6..8
+        Number@6..7 "2"
+        Whitespace@7..8 " "
+      RBrace@8..9 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_block_expr() {
+        check(
+            "{ 1;",
+            expect![[r#"
+Root@0..4
+  BlockExpr@0..4
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    Literal@2..3
+      Number@2..3 "1"
+    Semicolon@3..4 ";"
+error at 3..4: expected ‘}’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr() {
+        check(
+            "if a { 1 }",
+            expect![[r#"
+Root@0..10
+  IfExpr@0..10
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..10
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_else_expr() {
+        check(
+            "if a { 1 } else { 2 }",
+            expect![[r#"
+Root@0..21
+  IfExpr@0..21
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..11
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}"
+      Whitespace@10..11 " "
+    ElseKw@11..15 "else"
+    Whitespace@15..16 " "
+    BlockExpr@16..21
+      LBrace@16..17 "{"
+      Whitespace@17..18 " "
+      Literal@18..20
+        Number@18..19 "2"
+        Whitespace@19..20 " "
+      RBrace@20..21 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_else_if_chain() {
+        check(
+            "if a { 1 } else if b { 2 } else { 3 }",
+            expect![[r#"
+Root@0..37
+  IfExpr@0..37
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..11
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}"
+      Whitespace@10..11 " "
+    ElseKw@11..15 "else"
+    Whitespace@15..16 " "
+    IfExpr@16..37
+      IfKw@16..18 "if"
+      Whitespace@18..19 " "
+      VariableRef@19..21
+        Ident@19..20 "b"
+        Whitespace@20..21 " "
+      BlockExpr@21..27
+        LBrace@21..22 "{"
+        Whitespace@22..23 " "
+        Literal@23..25
+          Number@23..24 "2"
+          Whitespace@24..25 " "
+        RBrace@25..26 "}"
+        Whitespace@26..27 " "
+      ElseKw@27..31 "else"
+      Whitespace@31..32 " "
+      BlockExpr@32..37
+        LBrace@32..33 "{"
+        Whitespace@33..34 " "
+        Literal@34..36
+          Number@34..35 "3"
+          Whitespace@35..36 " "
+        RBrace@36..37 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr_with_infix_condition() {
+        check(
+            "if a - 1 { b }",
+            expect![[r#"
+Root@0..14
+  IfExpr@0..14
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    InfixExpr@3..9
+      VariableRef@3..5
+        Ident@3..4 "a"
+        Whitespace@4..5 " "
+      Minus@5..6 "-"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+    BlockExpr@9..14
+      LBrace@9..10 "{"
+      Whitespace@10..11 " "
+      VariableRef@11..13
+        Ident@11..12 "b"
+        Whitespace@12..13 " "
+      RBrace@13..14 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr_as_operand() {
+        check(
+            "1 + if a { 2 } else { 3 }",
+            expect![[r#"
+Root@0..25
+  InfixExpr@0..25
+    Literal@0..2
+      Number@0..1 "1"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    IfExpr@4..25
+      IfKw@4..6 "if"
+      Whitespace@6..7 " "
+      VariableRef@7..9
+        Ident@7..8 "a"
+        Whitespace@8..9 " "
+      BlockExpr@9..15
+        LBrace@9..10 "{"
+        Whitespace@10..11 " "
+        Literal@11..13
+          Number@11..12 "2"
+          Whitespace@12..13 " "
+        RBrace@13..14 "}"
+        Whitespace@14..15 " "
+      ElseKw@15..19 "else"
+      Whitespace@19..20 " "
+      BlockExpr@20..25
+        LBrace@20..21 "{"
+        Whitespace@21..22 " "
+        Literal@22..24
+          Number@22..23 "3"
+          Whitespace@23..24 " "
+        RBrace@24..25 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr_without_condition() {
+        check(
+            "if { 1 }",
+            expect![[r#"
+Root@0..8
+  IfExpr@0..8
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    BlockExpr@3..8
+      LBrace@3..4 "{"
+      Whitespace@4..5 " "
+      Literal@5..7
+        Number@5..6 "1"
+        Whitespace@6..7 " "
+      RBrace@7..8 "}"
+error at 7..8: expected ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr_without_then_branch() {
+        check(
+            "if a",
+            expect![[r#"
+Root@0..4
+  IfExpr@0..4
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..4
+      Ident@3..4 "a"
+error at 3..4: expected ‘::’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_expr_without_else_branch() {
+        check(
+            "if a { 1 } else",
+            expect![[r#"
+Root@0..15
+  IfExpr@0..15
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..11
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}"
+      Whitespace@10..11 " "
+    ElseKw@11..15 "else"
+error at 11..15: expected ‘if’ or ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_while_expr() {
+        check(
+            "while a { b }",
+            expect![[r#"
+Root@0..13
+  WhileExpr@0..13
+    WhileKw@0..5 "while"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    BlockExpr@8..13
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      VariableRef@10..12
+        Ident@10..11 "b"
+        Whitespace@11..12 " "
+      RBrace@12..13 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_while_expr_without_body() {
+        check(
+            "while a",
+            expect![[r#"
+Root@0..7
+  WhileExpr@0..7
+    WhileKw@0..5 "while"
+    Whitespace@5..6 " "
+    VariableRef@6..7
+      Ident@6..7 "a"
+error at 6..7: expected ‘::’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_loop_expr() {
+        check(
+            "loop { 1 }",
+            expect![[r#"
+Root@0..10
+  LoopExpr@0..10
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    BlockExpr@5..10
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Literal@7..9
+        Number@7..8 "1"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_loop_expr_without_body() {
+        check(
+            "loop 1",
+            expect![[r#"
+Root@0..6
+  LoopExpr@0..6
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    Error@5..6
+      Number@5..6 "1"
+error at 5..6: expected ‘{’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_break_expr() {
+        check(
+            "loop { break }",
+            expect![[r#"
+Root@0..14
+  LoopExpr@0..14
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    BlockExpr@5..14
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      BreakExpr@7..13
+        BreakKw@7..12 "break"
+        Whitespace@12..13 " "
+      RBrace@13..14 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_break_expr_with_value() {
+        check(
+            "loop { break a + 1 }",
+            expect![[r#"
+Root@0..20
+  LoopExpr@0..20
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    BlockExpr@5..20
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      BreakExpr@7..19
+        BreakKw@7..12 "break"
+        Whitespace@12..13 " "
+        InfixExpr@13..19
+          VariableRef@13..15
+            Ident@13..14 "a"
+            Whitespace@14..15 " "
+          Plus@15..16 "+"
+          Whitespace@16..17 " "
+          Literal@17..19
+            Number@17..18 "1"
+            Whitespace@18..19 " "
+      RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_break_expr_followed_by_semicolon() {
+        check(
+            "loop { break; }",
+            expect![[r#"
+Root@0..15
+  LoopExpr@0..15
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    BlockExpr@5..15
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      BreakExpr@7..12
+        BreakKw@7..12 "break"
+      Semicolon@12..13 ";"
+      Whitespace@13..14 " "
+      RBrace@14..15 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_continue_expr() {
+        check(
+            "while a { continue }",
+            expect![[r#"
+Root@0..20
+  WhileExpr@0..20
+    WhileKw@0..5 "while"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    BlockExpr@8..20
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      ContinueExpr@10..19
+        ContinueKw@10..18 "continue"
+        Whitespace@18..19 " "
+      RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_equality_expression() {
+        check(
+            "a == b",
+            expect![[r#"
+Root@0..6
+  InfixExpr@0..6
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    EqualsEquals@2..4 "=="
+    Whitespace@4..5 " "
+    VariableRef@5..6
+      Ident@5..6 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_comparison_operators() {
+        check(
+            "a < b != c >= d",
+            expect![[r#"
+Root@0..15
+  InfixExpr@0..15
+    InfixExpr@0..6
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      Less@2..3 "<"
+      Whitespace@3..4 " "
+      VariableRef@4..6
+        Ident@4..5 "b"
+        Whitespace@5..6 " "
+    BangEquals@6..8 "!="
+    Whitespace@8..9 " "
+    InfixExpr@9..15
+      VariableRef@9..11
+        Ident@9..10 "c"
+        Whitespace@10..11 " "
+      GreaterEquals@11..13 ">="
+      Whitespace@13..14 " "
+      VariableRef@14..15
+        Ident@14..15 "d""#]],
+        );
+    }
+
+    #[test]
+    fn comparison_operators_are_left_associative() {
+        check(
+            "a < b <= c",
+            expect![[r#"
+Root@0..10
+  InfixExpr@0..10
+    InfixExpr@0..6
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      Less@2..3 "<"
+      Whitespace@3..4 " "
+      VariableRef@4..6
+        Ident@4..5 "b"
+        Whitespace@5..6 " "
+    LessEquals@6..8 "<="
+    Whitespace@8..9 " "
+    VariableRef@9..10
+      Ident@9..10 "c""#]],
+        );
+    }
+
+    #[test]
+    fn equality_operators_are_left_associative() {
+        check(
+            "a == b != c",
+            expect![[r#"
+Root@0..11
+  InfixExpr@0..11
+    InfixExpr@0..7
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      EqualsEquals@2..4 "=="
+      Whitespace@4..5 " "
+      VariableRef@5..7
+        Ident@5..6 "b"
+        Whitespace@6..7 " "
+    BangEquals@7..9 "!="
+    Whitespace@9..10 " "
+    VariableRef@10..11
+      Ident@10..11 "c""#]],
+        );
+    }
+
+    #[test]
+    fn comparison_has_lower_binding_power_than_arithmetic() {
+        check(
+            "a + 1 > b * 2",
+            expect![[r#"
+Root@0..13
+  InfixExpr@0..13
+    InfixExpr@0..6
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      Plus@2..3 "+"
+      Whitespace@3..4 " "
+      Literal@4..6
+        Number@4..5 "1"
+        Whitespace@5..6 " "
+    Greater@6..7 ">"
+    Whitespace@7..8 " "
+    InfixExpr@8..13
+      VariableRef@8..10
+        Ident@8..9 "b"
+        Whitespace@9..10 " "
+      Star@10..11 "*"
+      Whitespace@11..12 " "
+      Literal@12..13
+        Number@12..13 "2""#]],
+        );
+    }
+
+    #[test]
+    fn equality_has_lower_binding_power_than_comparison() {
+        check(
+            "a > b == c < d",
+            expect![[r#"
+Root@0..14
+  InfixExpr@0..14
+    InfixExpr@0..6
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      Greater@2..3 ">"
+      Whitespace@3..4 " "
+      VariableRef@4..6
+        Ident@4..5 "b"
+        Whitespace@5..6 " "
+    EqualsEquals@6..8 "=="
+    Whitespace@8..9 " "
+    InfixExpr@9..14
+      VariableRef@9..11
+        Ident@9..10 "c"
+        Whitespace@10..11 " "
+      Less@11..12 "<"
+      Whitespace@12..13 " "
+      VariableRef@13..14
+        Ident@13..14 "d""#]],
+        );
+    }
+
+    #[test]
+    fn and_has_lower_binding_power_than_equality() {
+        check(
+            "a == b && c != d",
+            expect![[r#"
+Root@0..16
+  InfixExpr@0..16
+    InfixExpr@0..7
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      EqualsEquals@2..4 "=="
+      Whitespace@4..5 " "
+      VariableRef@5..7
+        Ident@5..6 "b"
+        Whitespace@6..7 " "
+    AndAnd@7..9 "&&"
+    Whitespace@9..10 " "
+    InfixExpr@10..16
+      VariableRef@10..12
+        Ident@10..11 "c"
+        Whitespace@11..12 " "
+      BangEquals@12..14 "!="
+      Whitespace@14..15 " "
+      VariableRef@15..16
+        Ident@15..16 "d""#]],
+        );
+    }
+
+    #[test]
+    fn or_has_lower_binding_power_than_and() {
+        check(
+            "a || b && c || d",
+            expect![[r#"
+Root@0..16
+  InfixExpr@0..16
+    InfixExpr@0..12
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      OrOr@2..4 "||"
+      Whitespace@4..5 " "
+      InfixExpr@5..12
+        VariableRef@5..7
+          Ident@5..6 "b"
+          Whitespace@6..7 " "
+        AndAnd@7..9 "&&"
+        Whitespace@9..10 " "
+        VariableRef@10..12
+          Ident@10..11 "c"
+          Whitespace@11..12 " "
+    OrOr@12..14 "||"
+    Whitespace@14..15 " "
+    VariableRef@15..16
+      Ident@15..16 "d""#]],
+        );
+    }
+
+    #[test]
+    fn parse_not() {
+        check(
+            "!a",
+            expect![[r#"
+Root@0..2
+  PrefixExpr@0..2
+    Bang@0..1 "!"
+    VariableRef@1..2
+      Ident@1..2 "a""#]],
+        );
+    }
+
+    #[test]
+    fn not_has_higher_binding_power_than_binary_operators() {
+        check(
+            "!a && !-b",
+            expect![[r#"
+Root@0..9
+  InfixExpr@0..9
+    PrefixExpr@0..3
+      Bang@0..1 "!"
+      VariableRef@1..3
+        Ident@1..2 "a"
+        Whitespace@2..3 " "
+    AndAnd@3..5 "&&"
+    Whitespace@5..6 " "
+    PrefixExpr@6..9
+      Bang@6..7 "!"
+      PrefixExpr@7..9
+        Minus@7..8 "-"
+        VariableRef@8..9
+          Ident@8..9 "b""#]],
+        );
+    }
+
+    #[test]
+    fn call_has_higher_binding_power_than_not() {
+        check(
+            "!f(a)",
+            expect![[r#"
+Root@0..5
+  PrefixExpr@0..5
+    Bang@0..1 "!"
+    CallExpr@1..5
+      VariableRef@1..2
+        Ident@1..2 "f"
+      ArgList@2..5
+        LParen@2..3 "("
+        VariableRef@3..4
+          Ident@3..4 "a"
+        RParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn recover_from_run_of_unexpected_tokens() {
+        check(
+            "a + ) ) )\nb",
+            expect![[r#"
+Root@0..11
+  InfixExpr@0..10
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    Error@4..10
+      RParen@4..5 ")"
+      Whitespace@5..6 " "
+      RParen@6..7 ")"
+      Whitespace@7..8 " "
+      RParen@8..9 ")"
+      Whitespace@9..10 "\n"
+  VariableRef@10..11
+    Ident@10..11 "b"
+error at 4..5: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_paren_expr() {
+        check(
+            "(a + * *)\nb",
+            expect![[r#"
+Root@0..11
+  ParenExpr@0..10
+    LParen@0..1 "("
+    InfixExpr@1..8
+      VariableRef@1..3
+        Ident@1..2 "a"
+        Whitespace@2..3 " "
+      Plus@3..4 "+"
+      Whitespace@4..5 " "
+      Error@5..8
+        Star@5..6 "*"
+        Whitespace@6..7 " "
+        Star@7..8 "*"
+    RParen@8..9 ")"
+    Whitespace@9..10 "\n"
+  VariableRef@10..11
+    Ident@10..11 "b"
+error at 5..6: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘*’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_brace_of_enclosing_block() {
+        check(
+            "{ (a }\nb",
+            expect![[r#"
+Root@0..8
+  BlockExpr@0..7
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    ParenExpr@2..5
+      LParen@2..3 "("
+      VariableRef@3..5
+        Ident@3..4 "a"
+        Whitespace@4..5 " "
+    RBrace@5..6 "}"
+    Whitespace@6..7 "\n"
+  VariableRef@7..8
+    Ident@7..8 "b"
+error at 5..6: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘)’ or ‘,’, but found ‘}’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_paren_of_enclosing_paren_expr() {
+        check(
+            "({ a )\nb",
+            expect![[r#"
+Root@0..8
+  ParenExpr@0..7
+    LParen@0..1 "("
+    BlockExpr@1..5
+      LBrace@1..2 "{"
+      Whitespace@2..3 " "
+      VariableRef@3..5
+        Ident@3..4 "a"
+        Whitespace@4..5 " "
+    RParen@5..6 ")"
+    Whitespace@6..7 "\n"
+  VariableRef@7..8
+    Ident@7..8 "b"
+error at 5..6: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘;’, ‘}’, ‘let’, ‘fn’, ‘struct’, ‘enum’, ‘mod’, ‘use’, number, float, string, ‘true’, ‘false’, identifier, ‘!’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’ or ‘|’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_block() {
+        check(
+            "{ let a = ; ) ) b }",
+            expect![[r#"
+Root@0..19
+  BlockExpr@0..19
+    LBrace@0..1 "{"
+    Whitespace@1..2 " "
+    VariableDef@2..10
+      LetKw@2..5 "let"
+      Whitespace@5..6 " "
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+      Equals@8..9 "="
+      Whitespace@9..10 " "
+    Semicolon@10..11 ";"
+    Whitespace@11..12 " "
+    Error@12..16
+      RParen@12..13 ")"
+      Whitespace@13..14 " "
+      RParen@14..15 ")"
+      Whitespace@15..16 " "
+    VariableRef@16..18
+      Ident@16..17 "b"
+      Whitespace@17..18 " "
+    RBrace@18..19 "}"
+error at 10..11: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘;’
+error at 12..13: expected ‘}’, ‘;’, ‘let’, ‘fn’, ‘struct’, ‘enum’, ‘mod’, ‘use’, number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’ or ‘||’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_arg_list() {
+        check(
+            "f(a, +, b)",
+            expect![[r#"
+Root@0..10
+  CallExpr@0..10
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..10
+      LParen@1..2 "("
+      VariableRef@2..3
+        Ident@2..3 "a"
+      Comma@3..4 ","
+      Whitespace@4..5 " "
+      Error@5..6
+        Plus@5..6 "+"
+      Comma@6..7 ","
+      Whitespace@7..8 " "
+      VariableRef@8..9
+        Ident@8..9 "b"
+      RParen@9..10 ")"
+error at 5..6: expected ‘)’, number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘+’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_arg_list_at_let() {
+        check(
+            "f(a\nlet b = 1",
+            expect![[r#"
+Root@0..13
+  CallExpr@0..4
+    VariableRef@0..1
+      Ident@0..1 "f"
+    ArgList@1..4
+      LParen@1..2 "("
+      VariableRef@2..4
+        Ident@2..3 "a"
+        Whitespace@3..4 "\n"
+  VariableDef@4..13
+    LetKw@4..7 "let"
+    Whitespace@7..8 " "
+    Ident@8..9 "b"
+    Whitespace@9..10 " "
+    Equals@10..11 "="
+    Whitespace@11..12 " "
+    Literal@12..13
+      Number@12..13 "1"
+error at 4..7: expected ‘::’, ‘{’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘)’ or ‘,’, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_before_if_body() {
+        check(
+            "if a ) { b }",
+            expect![[r#"
+Root@0..12
+  IfExpr@0..12
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    Error@5..7
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..12
+      LBrace@7..8 "{"
+      Whitespace@8..9 " "
+      VariableRef@9..11
+        Ident@9..10 "b"
+        Whitespace@10..11 " "
+      RBrace@11..12 "}"
+error at 5..6: expected ‘::’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘{’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_parens_nested_too_deeply() {
+        check_with_max_depth(
+            "(((a + 1)))\nb",
+            3,
+            expect![[r#"
+Root@0..13
+  ParenExpr@0..12
+    LParen@0..1 "("
+    ParenExpr@1..10
+      LParen@1..2 "("
+      ParenExpr@2..9
+        LParen@2..3 "("
+        Error@3..8
+          Ident@3..4 "a"
+          Whitespace@4..5 " "
+          Plus@5..6 "+"
+          Whitespace@6..7 " "
+          Number@7..8 "1"
+        RParen@8..9 ")"
+      RParen@9..10 ")"
+    RParen@10..11 ")"
+    Whitespace@11..12 "\n"
+  VariableRef@12..13
+    Ident@12..13 "b"
+error at 3..4: expression nested too deeply"#]],
+        );
+    }
+
+    #[test]
+    fn parse_prefix_exprs_nested_too_deeply() {
+        check_with_max_depth(
+            "- - - - 1",
+            3,
+            expect![[r#"
+Root@0..9
+  PrefixExpr@0..9
+    Minus@0..1 "-"
+    Whitespace@1..2 " "
+    PrefixExpr@2..9
+      Minus@2..3 "-"
+      Whitespace@3..4 " "
+      PrefixExpr@4..9
+        Minus@4..5 "-"
+        Whitespace@5..6 " "
+        Error@6..9
+          Minus@6..7 "-"
+          Whitespace@7..8 " "
+          Number@8..9 "1"
+error at 6..7: expression nested too deeply"#]],
+        );
+    }
+
+    #[test]
+    fn parse_binary_expr_chain_nested_too_deeply() {
+        check_with_max_depth(
+            "1 + 2 + 3 + 4",
+            3,
+            expect![[r#"
+Root@0..13
+  InfixExpr@0..13
+    InfixExpr@0..6
+      Literal@0..2
+        Number@0..1 "1"
+        Whitespace@1..2 " "
+      Plus@2..3 "+"
+      Whitespace@3..4 " "
+      Literal@4..6
+        Number@4..5 "2"
+        Whitespace@5..6 " "
+    Plus@6..7 "+"
+    Whitespace@7..8 " "
+    Error@8..13
+      Number@8..9 "3"
+      Whitespace@9..10 " "
+      Plus@10..11 "+"
+      Whitespace@11..12 " "
+      Number@12..13 "4"
+error at 8..9: expression nested too deeply"#]],
+        );
+    }
+
+    #[test]
+    fn parse_else_if_chain_nested_too_deeply() {
+        check_with_max_depth(
+            "if a {} else if b {} else if c {} else {}",
+            3,
+            expect![[r#"
+Root@0..41
+  IfExpr@0..41
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..8
+      LBrace@5..6 "{"
+      RBrace@6..7 "}"
+      Whitespace@7..8 " "
+    ElseKw@8..12 "else"
+    Whitespace@12..13 " "
+    IfExpr@13..41
+      IfKw@13..15 "if"
+      Whitespace@15..16 " "
+      VariableRef@16..18
+        Ident@16..17 "b"
+        Whitespace@17..18 " "
+      BlockExpr@18..21
+        LBrace@18..19 "{"
+        RBrace@19..20 "}"
+        Whitespace@20..21 " "
+      ElseKw@21..25 "else"
+      Whitespace@25..26 " "
+      IfExpr@26..41
+        IfKw@26..28 "if"
+        Whitespace@28..29 " "
+        Error@29..41
+          Ident@29..30 "c"
+          Whitespace@30..31 " "
+          LBrace@31..32 "{"
+          RBrace@32..33 "}"
+          Whitespace@33..34 " "
+          ElseKw@34..38 "else"
+          Whitespace@38..39 " "
+          LBrace@39..40 "{"
+          RBrace@40..41 "}"
+error at 29..30: expression nested too deeply"#]],
+        );
+    }
+
+    #[test]
+    fn parse_break_without_value_before_line_break() {
+        check(
+            "loop {\n  break\n  1\n}",
+            expect![[r#"
+Root@0..20
+  LoopExpr@0..20
+    LoopKw@0..4 "loop"
+    Whitespace@4..5 " "
+    BlockExpr@5..20
+      LBrace@5..6 "{"
+      Whitespace@6..9 "\n  "
+      BreakExpr@9..17
+        BreakKw@9..14 "break"
+        Whitespace@14..17 "\n  "
+      Literal@17..19
+        Number@17..18 "1"
+        Whitespace@18..19 "\n"
+      RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_with_statements_on_same_line() {
+        check(
+            "({ a b })",
+            expect![[r#"
+Root@0..9
+  ParenExpr@0..9
+    LParen@0..1 "("
+    BlockExpr@1..8
+      LBrace@1..2 "{"
+      Whitespace@2..3 " "
+      VariableRef@3..5
+        Ident@3..4 "a"
+        Whitespace@4..5 " "
+      VariableRef@5..7
+        Ident@5..6 "b"
+        Whitespace@6..7 " "
+      RBrace@7..8 "}"
+    RParen@8..9 ")"
+error at 5..6: expected newline or ‘;’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_assignment() {
+        check(
+            "a = 1 + 2",
+            expect![[r#"
+Root@0..9
+  AssignExpr@0..9
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    Equals@2..3 "="
+    Whitespace@3..4 " "
+    InfixExpr@4..9
+      Literal@4..6
+        Number@4..5 "1"
+        Whitespace@5..6 " "
+      Plus@6..7 "+"
+      Whitespace@7..8 " "
+      Literal@8..9
+        Number@8..9 "2""#]],
+        );
+    }
+
+    #[test]
+    fn parse_chained_assignment_is_right_associative() {
+        check(
+            "a = b = c",
+            expect![[r#"
+Root@0..9
+  AssignExpr@0..9
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    Equals@2..3 "="
+    Whitespace@3..4 " "
+    AssignExpr@4..9
+      VariableRef@4..6
+        Ident@4..5 "b"
+        Whitespace@5..6 " "
+      Equals@6..7 "="
+      Whitespace@7..8 " "
+      VariableRef@8..9
+        Ident@8..9 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_compound_assignments() {
+        check(
+            "a += 1\nb -= 2\nc *= 3\nd /= 4",
+            expect![[r#"
+Root@0..27
+  AssignExpr@0..7
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    PlusEquals@2..4 "+="
+    Whitespace@4..5 " "
+    Literal@5..7
+      Number@5..6 "1"
+      Whitespace@6..7 "\n"
+  AssignExpr@7..14
+    VariableRef@7..9
+      Ident@7..8 "b"
+      Whitespace@8..9 " "
+    MinusEquals@9..11 "-="
+    Whitespace@11..12 " "
+    Literal@12..14
+      Number@12..13 "2"
+      Whitespace@13..14 "\n"
+  AssignExpr@14..21
+    VariableRef@14..16
+      Ident@14..15 "c"
+      Whitespace@15..16 " "
+    StarEquals@16..18 "*="
+    Whitespace@18..19 " "
+    Literal@19..21
+      Number@19..20 "3"
+      Whitespace@20..21 "\n"
+  AssignExpr@21..27
+    VariableRef@21..23
+      Ident@21..22 "d"
+      Whitespace@22..23 " "
+    SlashEquals@23..25 "/="
+    Whitespace@25..26 " "
+    Literal@26..27
+      Number@26..27 "4""#]],
+        );
+    }
+
+    #[test]
+    fn parse_assignment_has_lowest_precedence() {
+        check(
+            "a || b = c",
+            expect![[r#"
+Root@0..10
+  AssignExpr@0..10
+    InfixExpr@0..7
+      VariableRef@0..2
+        Ident@0..1 "a"
+        Whitespace@1..2 " "
+      OrOr@2..4 "||"
+      Whitespace@4..5 " "
+      VariableRef@5..7
+        Ident@5..6 "b"
+        Whitespace@6..7 " "
+    Equals@7..8 "="
+    Whitespace@8..9 " "
+    VariableRef@9..10
+      Ident@9..10 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_assignment_without_value() {
+        check(
+            "a =",
+            expect![[r#"
+Root@0..3
+  AssignExpr@0..3
+    VariableRef@0..2
+      Ident@0..1 "a"
+      Whitespace@1..2 " "
+    Equals@2..3 "="
+error at 2..3: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuples() {
+        check(
+            "((), (a,), (a, b,))",
+            expect![[r#"
+Root@0..19
+  TupleExpr@0..19
+    LParen@0..1 "("
+    TupleExpr@1..3
+      LParen@1..2 "("
+      RParen@2..3 ")"
+    Comma@3..4 ","
+    Whitespace@4..5 " "
+    TupleExpr@5..9
+      LParen@5..6 "("
+      VariableRef@6..7
+        Ident@6..7 "a"
+      Comma@7..8 ","
+      RParen@8..9 ")"
+    Comma@9..10 ","
+    Whitespace@10..11 " "
+    TupleExpr@11..18
+      LParen@11..12 "("
+      VariableRef@12..13
+        Ident@12..13 "a"
+      Comma@13..14 ","
+      Whitespace@14..15 " "
+      VariableRef@15..16
+        Ident@15..16 "b"
+      Comma@16..17 ","
+      RParen@17..18 ")"
+    RParen@18..19 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuple_with_missing_field() {
+        check(
+            "(a, , b)",
+            expect![[r#"
+Root@0..8
+  TupleExpr@0..8
+    LParen@0..1 "("
+    VariableRef@1..2
+      Ident@1..2 "a"
+    Comma@2..3 ","
+    Whitespace@3..4 " "
+    Comma@4..5 ","
+    Whitespace@5..6 " "
+    VariableRef@6..7
+      Ident@6..7 "b"
+    RParen@7..8 ")"
+error at 4..5: expected ‘)’, number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_array() {
+        check(
+            "[1, 2, 3]",
+            expect![[r#"
+Root@0..9
+  ArrayExpr@0..9
+    LBracket@0..1 "["
+    Literal@1..2
+      Number@1..2 "1"
+    Comma@2..3 ","
+    Whitespace@3..4 " "
+    Literal@4..5
+      Number@4..5 "2"
+    Comma@5..6 ","
+    Whitespace@6..7 " "
+    Literal@7..8
+      Number@7..8 "3"
+    RBracket@8..9 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_array_and_trailing_comma() {
+        check(
+            "[[], [a,]]",
+            expect![[r#"
+Root@0..10
+  ArrayExpr@0..10
+    LBracket@0..1 "["
+    ArrayExpr@1..3
+      LBracket@1..2 "["
+      RBracket@2..3 "]"
+    Comma@3..4 ","
+    Whitespace@4..5 " "
+    ArrayExpr@5..9
+      LBracket@5..6 "["
+      VariableRef@6..7
+        Ident@6..7 "a"
+      Comma@7..8 ","
+      RBracket@8..9 "]"
+    RBracket@9..10 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_array_split_over_lines() {
+        check(
+            "[\n  1,\n  2\n]",
+            expect![[r#"
+Root@0..12
+  ArrayExpr@0..12
+    LBracket@0..1 "["
+    Whitespace@1..4 "\n  "
+    Literal@4..5
+      Number@4..5 "1"
+    Comma@5..6 ","
+    Whitespace@6..9 "\n  "
+    Literal@9..11
+      Number@9..10 "2"
+      Whitespace@10..11 "\n"
+    RBracket@11..12 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_index_expr() {
+        check(
+            "xs[i + 1][0]",
+            expect![[r#"
+Root@0..12
+  IndexExpr@0..12
+    IndexExpr@0..9
+      VariableRef@0..2
+        Ident@0..2 "xs"
+      LBracket@2..3 "["
+      InfixExpr@3..8
+        VariableRef@3..5
+          Ident@3..4 "i"
+          Whitespace@4..5 " "
+        Plus@5..6 "+"
+        Whitespace@6..7 " "
+        Literal@7..8
+          Number@7..8 "1"
+      RBracket@8..9 "]"
+    LBracket@9..10 "["
+    Literal@10..11
+      Number@10..11 "0"
+    RBracket@11..12 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_index_binds_tighter_than_prefix_operators() {
+        check(
+            "-xs[0]",
+            expect![[r#"
+Root@0..6
+  PrefixExpr@0..6
+    Minus@0..1 "-"
+    IndexExpr@1..6
+      VariableRef@1..3
+        Ident@1..3 "xs"
+      LBracket@3..4 "["
+      Literal@4..5
+        Number@4..5 "0"
+      RBracket@5..6 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_array_on_line_after_variable_ref() {
+        check(
+            "a\n[b]",
+            expect![[r#"
+Root@0..5
+  VariableRef@0..2
+    Ident@0..1 "a"
+    Whitespace@1..2 "\n"
+  ArrayExpr@2..5
+    LBracket@2..3 "["
+    VariableRef@3..4
+      Ident@3..4 "b"
+    RBracket@4..5 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_index_expr() {
+        check(
+            "xs[0",
+            expect![[r#"
+Root@0..4
+  IndexExpr@0..4
+    VariableRef@0..2
+      Ident@0..2 "xs"
+    LBracket@2..3 "["
+    Literal@3..4
+      Number@3..4 "0"
+error at 3..4: expected ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘]’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_paren_in_array() {
+        check(
+            "([a + )",
+            expect![[r#"
+Root@0..7
+  ParenExpr@0..7
+    LParen@0..1 "("
+    ArrayExpr@1..6
+      LBracket@1..2 "["
+      InfixExpr@2..6
+        VariableRef@2..4
+          Ident@2..3 "a"
+          Whitespace@3..4 " "
+        Plus@4..5 "+"
+        Whitespace@5..6 " "
+    RParen@6..7 ")"
+error at 6..7: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_record_expr() {
+        check(
+            "Point { x: 1, y: 2 }",
+            expect![[r#"
+Root@0..20
+  RecordExpr@0..20
+    Ident@0..5 "Point"
+    Whitespace@5..6 " "
+    RecordFieldList@6..20
+      LBrace@6..7 "{"
+      Whitespace@7..8 " "
+      RecordField@8..12
+        Ident@8..9 "x"
+        Colon@9..10 ":"
+        Whitespace@10..11 " "
+        Literal@11..12
+          Number@11..12 "1"
+      Comma@12..13 ","
+      Whitespace@13..14 " "
+      RecordField@14..19
+        Ident@14..15 "y"
+        Colon@15..16 ":"
+        Whitespace@16..17 " "
+        Literal@17..19
+          Number@17..18 "2"
+          Whitespace@18..19 " "
+      RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_record_expr() {
+        check(
+            "Unit {}",
+            expect![[r#"
+Root@0..7
+  RecordExpr@0..7
+    Ident@0..4 "Unit"
+    Whitespace@4..5 " "
+    RecordFieldList@5..7
+      LBrace@5..6 "{"
+      RBrace@6..7 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_record_expr_with_trailing_comma() {
+        check(
+            "Point {\n  x: 1,\n  y: 2,\n}",
+            expect![[r#"
+Root@0..25
+  RecordExpr@0..25
+    Ident@0..5 "Point"
+    Whitespace@5..6 " "
+    RecordFieldList@6..25
+      LBrace@6..7 "{"
+      Whitespace@7..10 "\n  "
+      RecordField@10..14
+        Ident@10..11 "x"
+        Colon@11..12 ":"
+        Whitespace@12..13 " "
+        Literal@13..14
+          Number@13..14 "1"
+      Comma@14..15 ","
+      Whitespace@15..18 "\n  "
+      RecordField@18..22
+        Ident@18..19 "y"
+        Colon@19..20 ":"
+        Whitespace@20..21 " "
+        Literal@21..22
+          Number@21..22 "2"
+      Comma@22..23 ","
+      Whitespace@23..24 "\n"
+      RBrace@24..25 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_record_expr() {
+        check(
+            "Line { start: Point { x: 0, y: 0 }, end: p }",
+            expect![[r#"
+Root@0..44
+  RecordExpr@0..44
+    Ident@0..4 "Line"
+    Whitespace@4..5 " "
+    RecordFieldList@5..44
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      RecordField@7..34
+        Ident@7..12 "start"
+        Colon@12..13 ":"
+        Whitespace@13..14 " "
+        RecordExpr@14..34
+          Ident@14..19 "Point"
+          Whitespace@19..20 " "
+          RecordFieldList@20..34
+            LBrace@20..21 "{"
+            Whitespace@21..22 " "
+            RecordField@22..26
+              Ident@22..23 "x"
+              Colon@23..24 ":"
+              Whitespace@24..25 " "
+              Literal@25..26
+                Number@25..26 "0"
+            Comma@26..27 ","
+            Whitespace@27..28 " "
+            RecordField@28..33
+              Ident@28..29 "y"
+              Colon@29..30 ":"
+              Whitespace@30..31 " "
+              Literal@31..33
+                Number@31..32 "0"
+                Whitespace@32..33 " "
+            RBrace@33..34 "}"
+      Comma@34..35 ","
+      Whitespace@35..36 " "
+      RecordField@36..43
+        Ident@36..39 "end"
+        Colon@39..40 ":"
+        Whitespace@40..41 " "
+        VariableRef@41..43
+          Ident@41..42 "p"
+          Whitespace@42..43 " "
+      RBrace@43..44 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_record_field_without_value() {
+        check(
+            "Point { x, y: 2 }",
+            expect![[r#"
+Root@0..17
+  RecordExpr@0..17
+    Ident@0..5 "Point"
+    Whitespace@5..6 " "
+    RecordFieldList@6..17
+      LBrace@6..7 "{"
+      Whitespace@7..8 " "
+      RecordField@8..9
+        Ident@8..9 "x"
+      Comma@9..10 ","
+      Whitespace@10..11 " "
+      RecordField@11..16
+        Ident@11..12 "y"
+        Colon@12..13 ":"
+        Whitespace@13..14 " "
+        Literal@14..16
+          Number@14..15 "2"
+          Whitespace@15..16 " "
+      RBrace@16..17 "}"
+error at 9..10: expected ‘:’, but found ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_paren_in_record_expr() {
+        check(
+            "(Point { x: 1 )",
+            expect![[r#"
+Root@0..15
+  ParenExpr@0..15
+    LParen@0..1 "("
+    RecordExpr@1..14
+      Ident@1..6 "Point"
+      Whitespace@6..7 " "
+      RecordFieldList@7..14
+        LBrace@7..8 "{"
+        Whitespace@8..9 " "
+        RecordField@9..14
+          Ident@9..10 "x"
+          Colon@10..11 ":"
+          Whitespace@11..12 " "
+          Literal@12..14
+            Number@12..13 "1"
+            Whitespace@13..14 " "
+    RParen@14..15 ")"
+error at 14..15: expected ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘}’ or ‘,’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_block_on_line_after_variable_ref() {
+        check(
+            "a\n{ b }",
+            expect![[r#"
+Root@0..7
+  VariableRef@0..2
+    Ident@0..1 "a"
+    Whitespace@1..2 "\n"
+  BlockExpr@2..7
+    LBrace@2..3 "{"
+    Whitespace@3..4 " "
+    VariableRef@4..6
+      Ident@4..5 "b"
+      Whitespace@5..6 " "
+    RBrace@6..7 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_condition_followed_by_block() {
+        check(
+            "if a { b }",
+            expect![[r#"
+Root@0..10
+  IfExpr@0..10
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    VariableRef@3..5
+      Ident@3..4 "a"
+      Whitespace@4..5 " "
+    BlockExpr@5..10
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      VariableRef@7..9
+        Ident@7..8 "b"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_record_expr_in_parens_in_if_condition() {
+        check(
+            "if (a == Point { x: 1 }) { b }",
+            expect![[r#"
+Root@0..30
+  IfExpr@0..30
+    IfKw@0..2 "if"
+    Whitespace@2..3 " "
+    ParenExpr@3..25
+      LParen@3..4 "("
+      InfixExpr@4..23
+        VariableRef@4..6
+          Ident@4..5 "a"
+          Whitespace@5..6 " "
+        EqualsEquals@6..8 "=="
+        Whitespace@8..9 " "
+        RecordExpr@9..23
+          Ident@9..14 "Point"
+          Whitespace@14..15 " "
+          RecordFieldList@15..23
+            LBrace@15..16 "{"
+            Whitespace@16..17 " "
+            RecordField@17..22
+              Ident@17..18 "x"
+              Colon@18..19 ":"
+              Whitespace@19..20 " "
+              Literal@20..22
+                Number@20..21 "1"
+                Whitespace@21..22 " "
+            RBrace@22..23 "}"
+      RParen@23..24 ")"
+      Whitespace@24..25 " "
+    BlockExpr@25..30
+      LBrace@25..26 "{"
+      Whitespace@26..27 " "
+      VariableRef@27..29
+        Ident@27..28 "b"
+        Whitespace@28..29 " "
+      RBrace@29..30 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr() {
+        check(
+            "p.x",
+            expect![[r#"
+Root@0..3
+  FieldExpr@0..3
+    VariableRef@0..1
+      Ident@0..1 "p"
+    Dot@1..2 "."
+    Ident@2..3 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr_chain() {
+        check(
+            "a.b.c",
+            expect![[r#"
+Root@0..5
+  FieldExpr@0..5
+    FieldExpr@0..3
+      VariableRef@0..1
+        Ident@0..1 "a"
+      Dot@1..2 "."
+      Ident@2..3 "b"
+    Dot@3..4 "."
+    Ident@4..5 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr_binds_tighter_than_prefix() {
+        check(
+            "-p.x",
+            expect![[r#"
+Root@0..4
+  PrefixExpr@0..4
+    Minus@0..1 "-"
+    FieldExpr@1..4
+      VariableRef@1..2
+        Ident@1..2 "p"
+      Dot@2..3 "."
+      Ident@3..4 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_on_field_expr() {
+        check(
+            "a.f(1)[0]",
+            expect![[r#"
+Root@0..9
+  IndexExpr@0..9
+    CallExpr@0..6
+      FieldExpr@0..3
+        VariableRef@0..1
+          Ident@0..1 "a"
+        Dot@1..2 "."
+        Ident@2..3 "f"
+      ArgList@3..6
+        LParen@3..4 "("
+        Literal@4..5
+          Number@4..5 "1"
+        RParen@5..6 ")"
+    LBracket@6..7 "["
+    Literal@7..8
+      Number@7..8 "0"
+    RBracket@8..9 "]""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr_on_record_expr() {
+        check(
+            "Point { x: 1 }.x",
+            expect![[r#"
+Root@0..16
+  FieldExpr@0..16
+    RecordExpr@0..14
+      Ident@0..5 "Point"
+      Whitespace@5..6 " "
+      RecordFieldList@6..14
+        LBrace@6..7 "{"
+        Whitespace@7..8 " "
+        RecordField@8..13
+          Ident@8..9 "x"
+          Colon@9..10 ":"
+          Whitespace@10..11 " "
+          Literal@11..13
+            Number@11..12 "1"
+            Whitespace@12..13 " "
+        RBrace@13..14 "}"
+    Dot@14..15 "."
+    Ident@15..16 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr_on_next_line() {
+        check(
+            "a\n  .b\n  .c",
+            expect![[r#"
+Root@0..11
+  FieldExpr@0..11
+    FieldExpr@0..9
+      VariableRef@0..4
+        Ident@0..1 "a"
+        Whitespace@1..4 "\n  "
+      Dot@4..5 "."
+      Ident@5..6 "b"
+      Whitespace@6..9 "\n  "
+    Dot@9..10 "."
+    Ident@10..11 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_expr_without_name() {
+        check(
+            "a. + 1",
+            expect![[r#"
+Root@0..6
+  FieldExpr@0..5
+    VariableRef@0..1
+      Ident@0..1 "a"
+    Dot@1..2 "."
+    Whitespace@2..3 " "
+    Error@3..5
+      Plus@3..4 "+"
+      Whitespace@4..5 " "
+  Literal@5..6
+    Number@5..6 "1"
+error at 3..4: expected identifier, but found ‘+’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_expr() {
+        check(
+            "match a { 1 => b, _ => c }",
+            expect![[r#"
+Root@0..26
+  MatchExpr@0..26
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..26
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..16
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..16
+          Ident@15..16 "b"
+      Comma@16..17 ","
+      Whitespace@17..18 " "
+      MatchArm@18..25
+        IdentPat@18..20
+          Ident@18..19 "_"
+          Whitespace@19..20 " "
+        FatArrow@20..22 "=>"
+        Whitespace@22..23 " "
+        VariableRef@23..25
+          Ident@23..24 "c"
+          Whitespace@24..25 " "
+      RBrace@25..26 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_expr_with_arms_on_separate_lines() {
+        check(
+            "match a {\n  1 => b\n  2 => { c }\n  _ => d\n}",
+            expect![[r#"
+Root@0..42
+  MatchExpr@0..42
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..42
+      LBrace@8..9 "{"
+      Whitespace@9..12 "\n  "
+      MatchArm@12..21
+        LiteralPat@12..14
+          Literal@12..14
+            Number@12..13 "1"
+            Whitespace@13..14 " "
+        FatArrow@14..16 "=>"
+        Whitespace@16..17 " "
+        VariableRef@17..21
+          Ident@17..18 "b"
+          Whitespace@18..21 "\n  "
+      MatchArm@21..34
+        LiteralPat@21..23
+          Literal@21..23
+            Number@21..22 "2"
+            Whitespace@22..23 " "
+        FatArrow@23..25 "=>"
+        Whitespace@25..26 " "
+        BlockExpr@26..34
+          LBrace@26..27 "{"
+          Whitespace@27..28 " "
+          VariableRef@28..30
+            Ident@28..29 "c"
+            Whitespace@29..30 " "
+          RBrace@30..31 "}"
+          Whitespace@31..34 "\n  "
+      MatchArm@34..41
+        IdentPat@34..36
+          Ident@34..35 "_"
+          Whitespace@35..36 " "
+        FatArrow@36..38 "=>"
+        Whitespace@38..39 " "
+        VariableRef@39..41
+          Ident@39..40 "d"
+          Whitespace@40..41 "\n"
+      RBrace@41..42 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arm_with_guard() {
+        check(
+            "match a { x if x > 0 => x, _ => 0 }",
+            expect![[r#"
+Root@0..35
+  MatchExpr@0..35
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..35
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..25
+        IdentPat@10..12
+          Ident@10..11 "x"
+          Whitespace@11..12 " "
+        MatchGuard@12..21
+          IfKw@12..14 "if"
+          Whitespace@14..15 " "
+          InfixExpr@15..21
+            VariableRef@15..17
+              Ident@15..16 "x"
+              Whitespace@16..17 " "
+            Greater@17..18 ">"
+            Whitespace@18..19 " "
+            Literal@19..21
+              Number@19..20 "0"
+              Whitespace@20..21 " "
+        FatArrow@21..23 "=>"
+        Whitespace@23..24 " "
+        VariableRef@24..25
+          Ident@24..25 "x"
+      Comma@25..26 ","
+      Whitespace@26..27 " "
+      MatchArm@27..34
+        IdentPat@27..29
+          Ident@27..28 "_"
+          Whitespace@28..29 " "
+        FatArrow@29..31 "=>"
+        Whitespace@31..32 " "
+        Literal@32..34
+          Number@32..33 "0"
+          Whitespace@33..34 " "
+      RBrace@34..35 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_match_expr() {
+        check(
+            "match a {}",
+            expect![[r#"
+Root@0..10
+  MatchExpr@0..10
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..10
+      LBrace@8..9 "{"
+      RBrace@9..10 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_on_record_expr_in_parens() {
+        check(
+            "match (Point { x: 1 }) { p => p.x }",
+            expect![[r#"
+Root@0..35
+  MatchExpr@0..35
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    ParenExpr@6..23
+      LParen@6..7 "("
+      RecordExpr@7..21
+        Ident@7..12 "Point"
+        Whitespace@12..13 " "
+        RecordFieldList@13..21
+          LBrace@13..14 "{"
+          Whitespace@14..15 " "
+          RecordField@15..20
+            Ident@15..16 "x"
+            Colon@16..17 ":"
+            Whitespace@17..18 " "
+            Literal@18..20
+              Number@18..19 "1"
+              Whitespace@19..20 " "
+          RBrace@20..21 "}"
+      RParen@21..22 ")"
+      Whitespace@22..23 " "
+    MatchArmList@23..35
+      LBrace@23..24 "{"
+      Whitespace@24..25 " "
+      MatchArm@25..34
+        IdentPat@25..27
+          Ident@25..26 "p"
+          Whitespace@26..27 " "
+        FatArrow@27..29 "=>"
+        Whitespace@29..30 " "
+        FieldExpr@30..34
+          VariableRef@30..31
+            Ident@30..31 "p"
+          Dot@31..32 "."
+          Ident@32..33 "x"
+          Whitespace@33..34 " "
+      RBrace@34..35 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_expr_without_arms() {
+        check(
+            "match a\nb",
+            expect![[r#"
+Root@0..9
+  MatchExpr@0..9
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 "\n"
+    Error@8..9
+      Ident@8..9 "b"
+error at 8..9: expected ‘::’, ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’ or ‘{’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arms_on_same_line() {
+        check(
+            "match a { 1 => b 2 => c }",
+            expect![[r#"
+Root@0..25
+  MatchExpr@0..25
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..25
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..17
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..17
+          Ident@15..16 "b"
+          Whitespace@16..17 " "
+      MatchArm@17..24
+        LiteralPat@17..19
+          Literal@17..19
+            Number@17..18 "2"
+            Whitespace@18..19 " "
+        FatArrow@19..21 "=>"
+        Whitespace@21..22 " "
+        VariableRef@22..24
+          Ident@22..23 "c"
+          Whitespace@23..24 " "
+      RBrace@24..25 "}"
+error at 17..18: expected newline or ‘,’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arm_after_block_on_same_line() {
+        check(
+            "match a { 1 => { b } 2 => c }",
+            expect![[r#"
+Root@0..29
+  MatchExpr@0..29
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..29
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..21
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        BlockExpr@15..21
+          LBrace@15..16 "{"
+          Whitespace@16..17 " "
+          VariableRef@17..19
+            Ident@17..18 "b"
+            Whitespace@18..19 " "
+          RBrace@19..20 "}"
+          Whitespace@20..21 " "
+      MatchArm@21..28
+        LiteralPat@21..23
+          Literal@21..23
+            Number@21..22 "2"
+            Whitespace@22..23 " "
+        FatArrow@23..25 "=>"
+        Whitespace@25..26 " "
+        VariableRef@26..28
+          Ident@26..27 "c"
+          Whitespace@27..28 " "
+      RBrace@28..29 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arm_after_record_expr_on_same_line() {
+        check(
+            "match a { 1 => P { x: b } 2 => c }",
+            expect![[r#"
+Root@0..34
+  MatchExpr@0..34
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..34
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..26
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        RecordExpr@15..26
+          Ident@15..16 "P"
+          Whitespace@16..17 " "
+          RecordFieldList@17..26
+            LBrace@17..18 "{"
+            Whitespace@18..19 " "
+            RecordField@19..24
+              Ident@19..20 "x"
+              Colon@20..21 ":"
+              Whitespace@21..22 " "
+              VariableRef@22..24
+                Ident@22..23 "b"
+                Whitespace@23..24 " "
+            RBrace@24..25 "}"
+            Whitespace@25..26 " "
+      MatchArm@26..33
+        LiteralPat@26..28
+          Literal@26..28
+            Number@26..27 "2"
+            Whitespace@27..28 " "
+        FatArrow@28..30 "=>"
+        Whitespace@30..31 " "
+        VariableRef@31..33
+          Ident@31..32 "c"
+          Whitespace@32..33 " "
+      RBrace@33..34 "}"
+error at 26..27: expected newline or ‘,’, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arm_without_fat_arrow() {
+        check(
+            "match a { 1 b, 2 => c }",
+            expect![[r#"
+Root@0..23
+  MatchExpr@0..23
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..23
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..13
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        Error@12..13
+          Ident@12..13 "b"
+      Comma@13..14 ","
+      Whitespace@14..15 " "
+      MatchArm@15..22
+        LiteralPat@15..17
+          Literal@15..17
+            Number@15..16 "2"
+            Whitespace@16..17 " "
+        FatArrow@17..19 "=>"
+        Whitespace@19..20 " "
+        VariableRef@20..22
+          Ident@20..21 "c"
+          Whitespace@21..22 " "
+      RBrace@22..23 "}"
+error at 12..13: expected ‘if’ or ‘=>’, but found identifier
+error at 13..14: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_match_arm_without_pattern() {
+        check(
+            "match a { => b }",
+            expect![[r#"
+Root@0..16
+  MatchExpr@0..16
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..16
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..15
+        FatArrow@10..12 "=>"
+        Whitespace@12..13 " "
+        VariableRef@13..15
+          Ident@13..14 "b"
+          Whitespace@14..15 " "
+      RBrace@15..16 "}"
+error at 10..12: expected ‘}’, number, float, string, ‘true’, ‘false’, identifier or ‘(’, but found ‘=>’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_paren_in_match_expr() {
+        check(
+            "(match a { 1 => )",
+            expect![[r#"
+Root@0..17
+  ParenExpr@0..17
+    LParen@0..1 "("
+    MatchExpr@1..16
+      MatchKw@1..6 "match"
+      Whitespace@6..7 " "
+      VariableRef@7..9
+        Ident@7..8 "a"
+        Whitespace@8..9 " "
+      MatchArmList@9..16
+        LBrace@9..10 "{"
+        Whitespace@10..11 " "
+        MatchArm@11..16
+          LiteralPat@11..13
+            Literal@11..13
+              Number@11..12 "1"
+              Whitespace@12..13 " "
+          FatArrow@13..15 "=>"
+          Whitespace@15..16 " "
+    RParen@16..17 ")"
+error at 16..17: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr() {
+        check(
+            "|x, y| x + y",
+            expect![[r#"
+Root@0..12
+  ClosureExpr@0..12
+    ParamList@0..7
+      Pipe@0..1 "|"
+      Param@1..2
+        Ident@1..2 "x"
+      Comma@2..3 ","
+      Whitespace@3..4 " "
+      Param@4..5
+        Ident@4..5 "y"
+      Pipe@5..6 "|"
+      Whitespace@6..7 " "
+    InfixExpr@7..12
+      VariableRef@7..9
+        Ident@7..8 "x"
+        Whitespace@8..9 " "
+      Plus@9..10 "+"
+      Whitespace@10..11 " "
+      VariableRef@11..12
+        Ident@11..12 "y""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_without_params() {
+        check(
+            "|| 1",
+            expect![[r#"
+Root@0..4
+  ClosureExpr@0..4
+    ParamList@0..3
+      OrOr@0..2 "||"
+      Whitespace@2..3 " "
+    Literal@3..4
+      Number@3..4 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_with_param_types() {
+        check(
+            "|x: Int| x",
+            expect![[r#"
+Root@0..10
+  ClosureExpr@0..10
+    ParamList@0..9
+      Pipe@0..1 "|"
+      Param@1..7
+        Ident@1..2 "x"
+        Colon@2..3 ":"
+        Whitespace@3..4 " "
+        TypeRef@4..7
+          Ident@4..7 "Int"
+      Pipe@7..8 "|"
+      Whitespace@8..9 " "
+    VariableRef@9..10
+      Ident@9..10 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_as_arg() {
+        check(
+            "map(a, |x| x * 2)",
+            expect![[r#"
+Root@0..17
+  CallExpr@0..17
+    VariableRef@0..3
+      Ident@0..3 "map"
+    ArgList@3..17
+      LParen@3..4 "("
+      VariableRef@4..5
+        Ident@4..5 "a"
+      Comma@5..6 ","
+      Whitespace@6..7 " "
+      ClosureExpr@7..16
+        ParamList@7..11
+          Pipe@7..8 "|"
+          Param@8..9
+            Ident@8..9 "x"
+          Pipe@9..10 "|"
+          Whitespace@10..11 " "
+        InfixExpr@11..16
+          VariableRef@11..13
+            Ident@11..12 "x"
+            Whitespace@12..13 " "
+          Star@13..14 "*"
+          Whitespace@14..15 " "
+          Literal@15..16
+            Number@15..16 "2"
+      RParen@16..17 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_returning_closure() {
+        check(
+            "|x| |y| x + y",
+            expect![[r#"
+Root@0..13
+  ClosureExpr@0..13
+    ParamList@0..4
+      Pipe@0..1 "|"
+      Param@1..2
+        Ident@1..2 "x"
+      Pipe@2..3 "|"
+      Whitespace@3..4 " "
+    ClosureExpr@4..13
+      ParamList@4..8
+        Pipe@4..5 "|"
+        Param@5..6
+          Ident@5..6 "y"
+        Pipe@6..7 "|"
+        Whitespace@7..8 " "
+      InfixExpr@8..13
+        VariableRef@8..10
+          Ident@8..9 "x"
+          Whitespace@9..10 " "
+        Plus@10..11 "+"
+        Whitespace@11..12 " "
+        VariableRef@12..13
+          Ident@12..13 "y""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_with_block_body() {
+        check(
+            "|x| {\n  let y = x\n  y\n}",
+            expect![[r#"
+Root@0..23
+  ClosureExpr@0..23
+    ParamList@0..4
+      Pipe@0..1 "|"
+      Param@1..2
+        Ident@1..2 "x"
+      Pipe@2..3 "|"
+      Whitespace@3..4 " "
+    BlockExpr@4..23
+      LBrace@4..5 "{"
+      Whitespace@5..8 "\n  "
+      VariableDef@8..20
+        LetKw@8..11 "let"
+        Whitespace@11..12 " "
+        Ident@12..13 "y"
+        Whitespace@13..14 " "
+        Equals@14..15 "="
+        Whitespace@15..16 " "
+        VariableRef@16..20
+          Ident@16..17 "x"
+          Whitespace@17..20 "\n  "
+      VariableRef@20..22
+        Ident@20..21 "y"
+        Whitespace@21..22 "\n"
+      RBrace@22..23 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_with_missing_closing_pipe() {
+        check(
+            "|x x",
+            expect![[r#"
+Root@0..4
+  ClosureExpr@0..4
+    ParamList@0..4
+      Pipe@0..1 "|"
+      Param@1..3
+        Ident@1..2 "x"
+        Whitespace@2..3 " "
+      Error@3..4
+        Ident@3..4 "x"
+error at 3..4: expected ‘:’, ‘|’ or ‘,’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_closure_expr_without_body() {
+        check(
+            "|x|",
+            expect![[r#"
+Root@0..3
+  ClosureExpr@0..3
+    ParamList@0..3
+      Pipe@0..1 "|"
+      Param@1..2
+        Ident@1..2 "x"
+      Pipe@2..3 "|"
+error at 2..3: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_closing_paren_in_closure_params() {
+        check(
+            "(|a )",
+            expect![[r#"
+Root@0..5
+  ParenExpr@0..5
+    LParen@0..1 "("
+    ClosureExpr@1..4
+      ParamList@1..4
+        Pipe@1..2 "|"
+        Param@2..4
+          Ident@2..3 "a"
+          Whitespace@3..4 " "
+    RParen@4..5 ")"
+error at 4..5: expected ‘:’, ‘|’ or ‘,’, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_closure_expr() {
+        check(
+            "let double = fn(x) => x * 2",
+            expect![[r#"
+Root@0..27
+  VariableDef@0..27
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..10 "double"
+    Whitespace@10..11 " "
+    Equals@11..12 "="
+    Whitespace@12..13 " "
+    ClosureExpr@13..27
+      FnKw@13..15 "fn"
+      ParamList@15..19
+        LParen@15..16 "("
+        Param@16..17
+          Ident@16..17 "x"
+        RParen@17..18 ")"
+        Whitespace@18..19 " "
+      FatArrow@19..21 "=>"
+      Whitespace@21..22 " "
+      InfixExpr@22..27
+        VariableRef@22..24
+          Ident@22..23 "x"
+          Whitespace@23..24 " "
+        Star@24..25 "*"
+        Whitespace@25..26 " "
+        Literal@26..27
+          Number@26..27 "2""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_closure_expr_as_statement() {
+        check(
+            "fn(x: Int) => x",
+            expect![[r#"
+Root@0..15
+  ClosureExpr@0..15
+    FnKw@0..2 "fn"
+    ParamList@2..11
+      LParen@2..3 "("
+      Param@3..9
+        Ident@3..4 "x"
+        Colon@4..5 ":"
+        Whitespace@5..6 " "
+        TypeRef@6..9
+          Ident@6..9 "Int"
+      RParen@9..10 ")"
+      Whitespace@10..11 " "
+    FatArrow@11..13 "=>"
+    Whitespace@13..14 " "
+    VariableRef@14..15
+      Ident@14..15 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_closure_expr_with_missing_fat_arrow() {
+        check(
+            "fn(x) x",
+            expect![[r#"
+Root@0..7
+  ClosureExpr@0..7
+    FnKw@0..2 "fn"
+    ParamList@2..6
+      LParen@2..3 "("
+      Param@3..4
+        Ident@3..4 "x"
+      RParen@4..5 ")"
+      Whitespace@5..6 " "
+    Error@6..7
+      Ident@6..7 "x"
+error at 6..7: expected ‘=>’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_closure_expr_with_missing_params() {
+        check(
+            "let f = fn => 1",
+            expect![[r#"
+Root@0..15
+  VariableDef@0..15
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "f"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    ClosureExpr@8..15
+      FnKw@8..10 "fn"
+      Whitespace@10..11 " "
+      FatArrow@11..13 "=>"
+      Whitespace@13..14 " "
+      Literal@14..15
+        Number@14..15 "1"
+error at 11..13: expected ‘(’, but found ‘=>’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_path_expr() {
+        check(
+            "a::b::c",
+            expect![[r#"
+Root@0..7
+  PathExpr@0..7
+    Path@0..7
+      Ident@0..1 "a"
+      ColonColon@1..3 "::"
+      Ident@3..4 "b"
+      ColonColon@4..6 "::"
+      Ident@6..7 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_to_path() {
+        check(
+            "shapes::area(s)",
+            expect![[r#"
+Root@0..15
+  CallExpr@0..15
+    PathExpr@0..12
+      Path@0..12
+        Ident@0..6 "shapes"
+        ColonColon@6..8 "::"
+        Ident@8..12 "area"
+    ArgList@12..15
+      LParen@12..13 "("
+      VariableRef@13..14
+        Ident@13..14 "s"
+      RParen@14..15 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_path_expr_in_binary_expr() {
+        check(
+            "a::b + 1",
+            expect![[r#"
+Root@0..8
+  InfixExpr@0..8
+    PathExpr@0..5
+      Path@0..5
+        Ident@0..1 "a"
+        ColonColon@1..3 "::"
+        Ident@3..4 "b"
+        Whitespace@4..5 " "
+    Plus@5..6 "+"
+    Whitespace@6..7 " "
+    Literal@7..8
+      Number@7..8 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_path_expr_with_missing_segment() {
+        check(
+            "(a:: )",
+            expect![[r#"
+Root@0..6
+  ParenExpr@0..6
+    LParen@0..1 "("
+    PathExpr@1..5
+      Path@1..5
+        Ident@1..2 "a"
+        ColonColon@2..4 "::"
+        Whitespace@4..5 " "
+    RParen@5..6 ")"
+error at 5..6: expected identifier, but found ‘)’"#]],
         );
     }
 }
@@ -0,0 +1,528 @@
+use super::*;
+
+/// The tokens a pattern can start with.
+const PAT_FIRST: &[TokenKind] = &[
+    TokenKind::Number,
+    TokenKind::Float,
+    TokenKind::String,
+    TokenKind::TrueKw,
+    TokenKind::FalseKw,
+    TokenKind::Ident,
+    TokenKind::LParen,
+];
+
+/// Returns whether a pattern starts at the current token, recording every token it could’ve
+/// started with otherwise so that they show up in the error.
+pub(super) fn at_pat_start(p: &mut Parser) -> bool {
+    PAT_FIRST.iter().any(|&kind| p.at(kind))
+}
+
+/// Parses a pattern, which is a literal, a name, a tuple pattern, e.g. `(a, _)`, or a variant
+/// pattern, e.g. `Rect(w, h)`. A name on its own binds whatever it’s matched against, except for
+/// `_`, which is left to lowering to tell apart.
+pub(super) fn pat(p: &mut Parser) -> Option<CompletedMarker> {
+    // Patterns can nest without going through an expression.
+    if !p.enter() {
+        return None;
+    }
+
+    let cm = if p.at(TokenKind::Number)
+        || p.at(TokenKind::Float)
+        || p.at(TokenKind::String)
+        || p.at(TokenKind::TrueKw)
+        || p.at(TokenKind::FalseKw)
+    {
+        Some(literal_pat(p))
+    } else if p.at(TokenKind::Ident) {
+        Some(ident_or_variant_pat(p))
+    } else if p.at(TokenKind::LParen) {
+        Some(tuple_pat(p))
+    } else {
+        p.error();
+        None
+    };
+
+    p.exit();
+    cm
+}
+
+fn literal_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    expr::literal(p);
+    m.complete(p, SyntaxKind::LiteralPat)
+}
+
+fn ident_or_variant_pat(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::Ident));
+
+    let m = p.start();
+    p.bump();
+
+    if p.at(TokenKind::LParen) {
+        pat_list(p);
+        return m.complete(p, SyntaxKind::VariantPat);
+    }
+
+    m.complete(p, SyntaxKind::IdentPat)
+}
+
+/// Parses a tuple pattern. Like tuple expressions, a pattern in parentheses is only a tuple pattern
+/// if there’s a comma, e.g. `(a,)`, or nothing at all, i.e. `()`.
+fn tuple_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+
+    let kind = if pat_list(p) {
+        SyntaxKind::TuplePat
+    } else {
+        SyntaxKind::ParenPat
+    };
+
+    m.complete(p, kind)
+}
+
+/// Parses patterns separated by commas inside parentheses, returning whether they make up a tuple.
+fn pat_list(p: &mut Parser) -> bool {
+    assert!(p.at(TokenKind::LParen));
+    p.bump();
+
+    let is_empty = p.at(TokenKind::RParen);
+    let has_comma = delimited(p, TokenKind::RParen, pat);
+
+    is_empty || has_comma
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check;
+    use expect_test::expect;
+
+    #[test]
+    fn parse_literal_pats() {
+        check(
+            "match a { 1 => a, 1.5 => b, \"s\" => c, true => d }",
+            expect![[r#"
+Root@0..49
+  MatchExpr@0..49
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..49
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..16
+        LiteralPat@10..12
+          Literal@10..12
+            Number@10..11 "1"
+            Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..16
+          Ident@15..16 "a"
+      Comma@16..17 ","
+      Whitespace@17..18 " "
+      MatchArm@18..26
+        LiteralPat@18..22
+          Literal@18..22
+            Float@18..21 "1.5"
+            Whitespace@21..22 " "
+        FatArrow@22..24 "=>"
+        Whitespace@24..25 " "
+        VariableRef@25..26
+          Ident@25..26 "b"
+      Comma@26..27 ","
+      Whitespace@27..28 " "
+      MatchArm@28..36
+        LiteralPat@28..32
+          Literal@28..32
+            String@28..31 "\"s\""
+            Whitespace@31..32 " "
+        FatArrow@32..34 "=>"
+        Whitespace@34..35 " "
+        VariableRef@35..36
+          Ident@35..36 "c"
+      Comma@36..37 ","
+      Whitespace@37..38 " "
+      MatchArm@38..48
+        LiteralPat@38..43
+          Literal@38..43
+            TrueKw@38..42 "true"
+            Whitespace@42..43 " "
+        FatArrow@43..45 "=>"
+        Whitespace@45..46 " "
+        VariableRef@46..48
+          Ident@46..47 "d"
+          Whitespace@47..48 " "
+      RBrace@48..49 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_ident_pat() {
+        check(
+            "match a { b => b }",
+            expect![[r#"
+Root@0..18
+  MatchExpr@0..18
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..18
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..17
+        IdentPat@10..12
+          Ident@10..11 "b"
+          Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..17
+          Ident@15..16 "b"
+          Whitespace@16..17 " "
+      RBrace@17..18 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_wildcard_pat() {
+        check(
+            "match a { _ => b }",
+            expect![[r#"
+Root@0..18
+  MatchExpr@0..18
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..18
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..17
+        IdentPat@10..12
+          Ident@10..11 "_"
+          Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..17
+          Ident@15..16 "b"
+          Whitespace@16..17 " "
+      RBrace@17..18 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuple_pat() {
+        check(
+            "match a { (b, _, 1) => b }",
+            expect![[r#"
+Root@0..26
+  MatchExpr@0..26
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..26
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..25
+        TuplePat@10..20
+          LParen@10..11 "("
+          IdentPat@11..12
+            Ident@11..12 "b"
+          Comma@12..13 ","
+          Whitespace@13..14 " "
+          IdentPat@14..15
+            Ident@14..15 "_"
+          Comma@15..16 ","
+          Whitespace@16..17 " "
+          LiteralPat@17..18
+            Literal@17..18
+              Number@17..18 "1"
+          RParen@18..19 ")"
+          Whitespace@19..20 " "
+        FatArrow@20..22 "=>"
+        Whitespace@22..23 " "
+        VariableRef@23..25
+          Ident@23..24 "b"
+          Whitespace@24..25 " "
+      RBrace@25..26 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_one_element_tuple_pat() {
+        check(
+            "match a { (b,) => b }",
+            expect![[r#"
+Root@0..21
+  MatchExpr@0..21
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..21
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..20
+        TuplePat@10..15
+          LParen@10..11 "("
+          IdentPat@11..12
+            Ident@11..12 "b"
+          Comma@12..13 ","
+          RParen@13..14 ")"
+          Whitespace@14..15 " "
+        FatArrow@15..17 "=>"
+        Whitespace@17..18 " "
+        VariableRef@18..20
+          Ident@18..19 "b"
+          Whitespace@19..20 " "
+      RBrace@20..21 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unit_pat() {
+        check(
+            "match a { () => b }",
+            expect![[r#"
+Root@0..19
+  MatchExpr@0..19
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..19
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..18
+        TuplePat@10..13
+          LParen@10..11 "("
+          RParen@11..12 ")"
+          Whitespace@12..13 " "
+        FatArrow@13..15 "=>"
+        Whitespace@15..16 " "
+        VariableRef@16..18
+          Ident@16..17 "b"
+          Whitespace@17..18 " "
+      RBrace@18..19 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_paren_pat() {
+        check(
+            "match a { (b) => b }",
+            expect![[r#"
+Root@0..20
+  MatchExpr@0..20
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..20
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..19
+        ParenPat@10..14
+          LParen@10..11 "("
+          IdentPat@11..12
+            Ident@11..12 "b"
+          RParen@12..13 ")"
+          Whitespace@13..14 " "
+        FatArrow@14..16 "=>"
+        Whitespace@16..17 " "
+        VariableRef@17..19
+          Ident@17..18 "b"
+          Whitespace@18..19 " "
+      RBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_variant_pat() {
+        check(
+            "match s { Rect(w, h) => w * h }",
+            expect![[r#"
+Root@0..31
+  MatchExpr@0..31
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "s"
+      Whitespace@7..8 " "
+    MatchArmList@8..31
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..30
+        VariantPat@10..21
+          Ident@10..14 "Rect"
+          LParen@14..15 "("
+          IdentPat@15..16
+            Ident@15..16 "w"
+          Comma@16..17 ","
+          Whitespace@17..18 " "
+          IdentPat@18..19
+            Ident@18..19 "h"
+          RParen@19..20 ")"
+          Whitespace@20..21 " "
+        FatArrow@21..23 "=>"
+        Whitespace@23..24 " "
+        InfixExpr@24..30
+          VariableRef@24..26
+            Ident@24..25 "w"
+            Whitespace@25..26 " "
+          Star@26..27 "*"
+          Whitespace@27..28 " "
+          VariableRef@28..30
+            Ident@28..29 "h"
+            Whitespace@29..30 " "
+      RBrace@30..31 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_variant_pat_without_fields() {
+        check(
+            "match s { Empty() => 0 }",
+            expect![[r#"
+Root@0..24
+  MatchExpr@0..24
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "s"
+      Whitespace@7..8 " "
+    MatchArmList@8..24
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..23
+        VariantPat@10..18
+          Ident@10..15 "Empty"
+          LParen@15..16 "("
+          RParen@16..17 ")"
+          Whitespace@17..18 " "
+        FatArrow@18..20 "=>"
+        Whitespace@20..21 " "
+        Literal@21..23
+          Number@21..22 "0"
+          Whitespace@22..23 " "
+      RBrace@23..24 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_pats() {
+        check(
+            "match s { Some((a, Rect(_, h))) => h }",
+            expect![[r#"
+Root@0..38
+  MatchExpr@0..38
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "s"
+      Whitespace@7..8 " "
+    MatchArmList@8..38
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..37
+        VariantPat@10..32
+          Ident@10..14 "Some"
+          LParen@14..15 "("
+          TuplePat@15..30
+            LParen@15..16 "("
+            IdentPat@16..17
+              Ident@16..17 "a"
+            Comma@17..18 ","
+            Whitespace@18..19 " "
+            VariantPat@19..29
+              Ident@19..23 "Rect"
+              LParen@23..24 "("
+              IdentPat@24..25
+                Ident@24..25 "_"
+              Comma@25..26 ","
+              Whitespace@26..27 " "
+              IdentPat@27..28
+                Ident@27..28 "h"
+              RParen@28..29 ")"
+            RParen@29..30 ")"
+          RParen@30..31 ")"
+          Whitespace@31..32 " "
+        FatArrow@32..34 "=>"
+        Whitespace@34..35 " "
+        VariableRef@35..37
+          Ident@35..36 "h"
+          Whitespace@36..37 " "
+      RBrace@37..38 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_invalid_pat() {
+        check(
+            "match a { + => b }",
+            expect![[r#"
+Root@0..18
+  MatchExpr@0..18
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..18
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..17
+        Error@10..12
+          Plus@10..11 "+"
+          Whitespace@11..12 " "
+        FatArrow@12..14 "=>"
+        Whitespace@14..15 " "
+        VariableRef@15..17
+          Ident@15..16 "b"
+          Whitespace@16..17 " "
+      RBrace@17..18 "}"
+error at 10..11: expected ‘}’, number, float, string, ‘true’, ‘false’, identifier or ‘(’, but found ‘+’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_fat_arrow_in_tuple_pat() {
+        check(
+            "match a { (b, => c }",
+            expect![[r#"
+Root@0..20
+  MatchExpr@0..20
+    MatchKw@0..5 "match"
+    Whitespace@5..6 " "
+    VariableRef@6..8
+      Ident@6..7 "a"
+      Whitespace@7..8 " "
+    MatchArmList@8..20
+      LBrace@8..9 "{"
+      Whitespace@9..10 " "
+      MatchArm@10..19
+        TuplePat@10..14
+          LParen@10..11 "("
+          IdentPat@11..12
+            Ident@11..12 "b"
+          Comma@12..13 ","
+          Whitespace@13..14 " "
+        FatArrow@14..16 "=>"
+        Whitespace@16..17 " "
+        VariableRef@17..19
+          Ident@17..18 "c"
+          Whitespace@18..19 " "
+      RBrace@19..20 "}"
+error at 14..16: expected ‘)’, number, float, string, ‘true’, ‘false’, identifier or ‘(’, but found ‘=>’"#]],
+        );
+    }
+}
@@ -3,18 +3,78 @@ use super::*;
 pub(super) fn stmt(p: &mut Parser) -> Option<CompletedMarker> {
     if p.at(TokenKind::LetKw) {
         Some(variable_def(p))
+    } else if p.at(TokenKind::FnKw) && !p.next_at(TokenKind::LParen) {
+        // Otherwise, `fn` starts a closure, e.g. `fn(x) => x * 2`.
+        Some(fn_def(p))
+    } else if p.at(TokenKind::StructKw) {
+        Some(struct_def(p))
+    } else if p.at(TokenKind::EnumKw) {
+        Some(enum_def(p))
+    } else if p.at(TokenKind::ModKw) {
+        Some(mod_def(p))
+    } else if p.at(TokenKind::UseKw) {
+        Some(use_def(p))
     } else {
         expr::expr(p)
     }
 }
 
+/// Parses a statement along with the `;` ending it, if there is one. Otherwise, the statement has
+/// to be the last one on its line or before a closing bracket, unless it’s block-like itself, e.g.
+/// `if a { b } c` is fine.
+pub(super) fn terminated_stmt(p: &mut Parser) -> Option<CompletedMarker> {
+    let num_errors = p.num_errors();
+    let cm = stmt(p)?;
+
+    if p.at(TokenKind::Semicolon) {
+        p.bump();
+    } else if !p.at_end()
+        // A closing bracket is left to whatever it closes, which reports it if it’s unexpected.
+        && !p.at_set(&[TokenKind::RBrace, TokenKind::RParen])
+        && !p.at_line_break()
+        && !is_block_like(cm.kind())
+        // Whatever follows a statement that’s already been reported is likely to be part of the
+        // same mistake.
+        && p.num_errors() == num_errors
+    {
+        p.error_missing_terminator(TokenKind::Semicolon);
+    }
+
+    Some(cm)
+}
+
+/// Returns whether a statement of the given kind is block-like, i.e. it’s a definition or an
+/// expression made up of blocks, e.g. an ‘if’, so it ends clearly enough that it doesn’t need to be
+/// followed by a line break or `;`. Other statements ending with a block, e.g. `let a = { 1 }`,
+/// still do.
+pub(super) fn is_block_like(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::BlockExpr
+            | SyntaxKind::IfExpr
+            | SyntaxKind::WhileExpr
+            | SyntaxKind::LoopExpr
+            | SyntaxKind::MatchExpr
+            | SyntaxKind::FnDef
+            | SyntaxKind::StructDef
+            | SyntaxKind::EnumDef
+    )
+}
+
 fn variable_def(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(TokenKind::LetKw));
 
     let m = p.start();
     p.bump();
 
+    p.push_recovery_set(&[TokenKind::Colon, TokenKind::Equals]);
     p.expect(TokenKind::Ident);
+    p.pop_recovery_set();
+
+    p.push_recovery_set(&[TokenKind::Equals]);
+    ty::ascription(p);
+    p.pop_recovery_set();
+
     p.expect(TokenKind::Equals);
 
     expr::expr(p);
@@ -22,9 +82,191 @@ fn variable_def(p: &mut Parser) -> CompletedMarker {
     m.complete(p, SyntaxKind::VariableDef)
 }
 
+fn fn_def(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::FnKw));
+
+    let m = p.start();
+    p.bump();
+
+    p.push_recovery_set(&[TokenKind::LParen, TokenKind::LBrace]);
+    p.expect(TokenKind::Ident);
+    p.pop_recovery_set();
+
+    p.push_recovery_set(&[TokenKind::LBrace]);
+
+    if p.at(TokenKind::LParen) {
+        param_list(p);
+    } else {
+        p.error();
+    }
+
+    if p.at(TokenKind::Arrow) {
+        p.bump();
+        ty::ty(p);
+    }
+
+    p.pop_recovery_set();
+
+    expr::block_or_error(p);
+
+    m.complete(p, SyntaxKind::FnDef)
+}
+
+/// Parses a module declaration, e.g. `mod shapes`, which refers to the module in `shapes.chou`
+/// next to the current file.
+fn mod_def(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::ModKw));
+
+    let m = p.start();
+    p.bump();
+    p.expect(TokenKind::Ident);
+    m.complete(p, SyntaxKind::ModDef)
+}
+
+fn use_def(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::UseKw));
+
+    let m = p.start();
+    p.bump();
+
+    if p.at(TokenKind::Ident) {
+        expr::path(p);
+    } else {
+        p.error();
+    }
+
+    m.complete(p, SyntaxKind::UseDef)
+}
+
+pub(super) fn param_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LParen));
+
+    let m = p.start();
+    p.bump();
+
+    delimited(p, TokenKind::RParen, param_or_error);
+
+    m.complete(p, SyntaxKind::ParamList)
+}
+
+pub(super) fn param_or_error(p: &mut Parser) -> Option<CompletedMarker> {
+    if !p.at(TokenKind::Ident) {
+        p.error();
+        return None;
+    }
+
+    let m = p.start();
+    p.bump();
+    ty::ascription(p);
+    Some(m.complete(p, SyntaxKind::Param))
+}
+
+fn struct_def(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::StructKw));
+
+    let m = p.start();
+    p.bump();
+
+    p.push_recovery_set(&[TokenKind::LBrace]);
+    p.expect(TokenKind::Ident);
+    p.pop_recovery_set();
+
+    if p.at(TokenKind::LBrace) {
+        field_def_list(p);
+    } else {
+        p.error();
+    }
+
+    m.complete(p, SyntaxKind::StructDef)
+}
+
+fn field_def_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBrace));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    delimited(p, TokenKind::RBrace, field_def_or_error);
+    p.set_newlines_significant(newlines_significant);
+
+    m.complete(p, SyntaxKind::FieldDefList)
+}
+
+/// Parses a field of a struct definition. Unlike bindings and parameters, fields always need a
+/// type.
+fn field_def_or_error(p: &mut Parser) -> Option<CompletedMarker> {
+    if !p.at(TokenKind::Ident) {
+        p.error();
+        return None;
+    }
+
+    let m = p.start();
+    p.bump();
+
+    if p.at(TokenKind::Colon) {
+        ty::ascription(p);
+    } else {
+        p.error();
+    }
+
+    Some(m.complete(p, SyntaxKind::FieldDef))
+}
+
+fn enum_def(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::EnumKw));
+
+    let m = p.start();
+    p.bump();
+
+    p.push_recovery_set(&[TokenKind::LBrace]);
+    p.expect(TokenKind::Ident);
+    p.pop_recovery_set();
+
+    if p.at(TokenKind::LBrace) {
+        variant_list(p);
+    } else {
+        p.error();
+    }
+
+    m.complete(p, SyntaxKind::EnumDef)
+}
+
+fn variant_list(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LBrace));
+
+    let m = p.start();
+    p.bump();
+
+    let newlines_significant = p.set_newlines_significant(false);
+    delimited(p, TokenKind::RBrace, variant_or_error);
+    p.set_newlines_significant(newlines_significant);
+
+    m.complete(p, SyntaxKind::VariantList)
+}
+
+/// Parses a variant of an enum definition. A variant’s fields are written like a function’s
+/// parameters, e.g. `Rect(w: Float, h: Float)`, and a variant without any can leave out the
+/// parentheses.
+fn variant_or_error(p: &mut Parser) -> Option<CompletedMarker> {
+    if !p.at(TokenKind::Ident) {
+        p.error();
+        return None;
+    }
+
+    let m = p.start();
+    p.bump();
+
+    if p.at(TokenKind::LParen) {
+        param_list(p);
+    }
+
+    Some(m.complete(p, SyntaxKind::Variant))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::check;
+    use crate::{check, check_with_max_depth};
     use expect_test::expect;
 
     #[test]
@@ -45,6 +287,114 @@ Root@0..13
         );
     }
 
+    #[test]
+    fn parse_variable_definition_with_doc_comment() {
+        check(
+            "let a = 1\n## The answer.\n##\n## Really.\nlet b = 42",
+            expect![[r###"
+Root@0..49
+  VariableDef@0..10
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    Literal@8..10
+      Number@8..9 "1"
+      Whitespace@9..10 "\n"
+  VariableDef@10..49
+    DocComment@10..24 "## The answer."
+    Whitespace@24..25 "\n"
+    DocComment@25..27 "##"
+    Whitespace@27..28 "\n"
+    DocComment@28..38 "## Really."
+    Whitespace@38..39 "\n"
+    LetKw@39..42 "let"
+    Whitespace@42..43 " "
+    Ident@43..44 "b"
+    Whitespace@44..45 " "
+    Equals@45..46 "="
+    Whitespace@46..47 " "
+    Literal@47..49
+      Number@47..49 "42""###]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_doc_comment_in_block() {
+        check(
+            "{\n  ## Doubles `x`.\n  fn double(x) { x * 2 }\n}",
+            expect![[r###"
+Root@0..46
+  BlockExpr@0..46
+    LBrace@0..1 "{"
+    Whitespace@1..4 "\n  "
+    FnDef@4..45
+      DocComment@4..19 "## Doubles `x`."
+      Whitespace@19..22 "\n  "
+      FnKw@22..24 "fn"
+      Whitespace@24..25 " "
+      Ident@25..31 "double"
+      ParamList@31..35
+        LParen@31..32 "("
+        Param@32..33
+          Ident@32..33 "x"
+        RParen@33..34 ")"
+        Whitespace@34..35 " "
+      BlockExpr@35..45
+        LBrace@35..36 "{"
+        Whitespace@36..37 " "
+        InfixExpr@37..43
+          VariableRef@37..39
+            Ident@37..38 "x"
+            Whitespace@38..39 " "
+          Star@39..40 "*"
+          Whitespace@40..41 " "
+          Literal@41..43
+            Number@41..42 "2"
+            Whitespace@42..43 " "
+        RBrace@43..44 "}"
+        Whitespace@44..45 "\n"
+    RBrace@45..46 "}""###]],
+        );
+    }
+
+    #[test]
+    fn parse_doc_comment_separated_by_comment() {
+        check(
+            "## Not attached.\n# comment\nlet a = 1",
+            expect![[r###"
+Root@0..36
+  DocComment@0..16 "## Not attached."
+  Whitespace@16..17 "\n"
+  Comment@17..26 "# comment"
+  Whitespace@26..27 "\n"
+  VariableDef@27..36
+    LetKw@27..30 "let"
+    Whitespace@30..31 " "
+    Ident@31..32 "a"
+    Whitespace@32..33 " "
+    Equals@33..34 "="
+    Whitespace@34..35 " "
+    Literal@35..36
+      Number@35..36 "1""###]],
+        );
+    }
+
+    #[test]
+    fn parse_doc_comment_before_expr() {
+        check(
+            "## Not attached.\na",
+            expect![[r###"
+Root@0..18
+  DocComment@0..16 "## Not attached."
+  Whitespace@16..17 "\n"
+  VariableRef@17..18
+    Ident@17..18 "a""###]],
+        );
+    }
+
     #[test]
     fn recover_on_let_token() {
         check(
@@ -67,7 +417,902 @@ Root@0..17
     Whitespace@15..16 " "
     VariableRef@16..17
       Ident@16..17 "a"
-error at 8..11: expected number, identifier, ‘-’ or ‘(’, but found ‘let’"#]],
+error at 8..11: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def() {
+        check(
+            "fn add(a, b) { a + b }",
+            expect![[r#"
+Root@0..22
+  FnDef@0..22
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..6 "add"
+    ParamList@6..13
+      LParen@6..7 "("
+      Param@7..8
+        Ident@7..8 "a"
+      Comma@8..9 ","
+      Whitespace@9..10 " "
+      Param@10..11
+        Ident@10..11 "b"
+      RParen@11..12 ")"
+      Whitespace@12..13 " "
+    BlockExpr@13..22
+      LBrace@13..14 "{"
+      Whitespace@14..15 " "
+      InfixExpr@15..21
+        VariableRef@15..17
+          Ident@15..16 "a"
+          Whitespace@16..17 " "
+        Plus@17..18 "+"
+        Whitespace@18..19 " "
+        VariableRef@19..21
+          Ident@19..20 "b"
+          Whitespace@20..21 " "
+      RBrace@21..22 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_without_params() {
+        check(
+            "fn f() {}",
+            expect![[r#"
+Root@0..9
+  FnDef@0..9
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..9
+      LBrace@7..8 "{"
+      RBrace@8..9 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_trailing_comma_in_params() {
+        check(
+            "fn f(a,) {}",
+            expect![[r#"
+Root@0..11
+  FnDef@0..11
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..9
+      LParen@4..5 "("
+      Param@5..6
+        Ident@5..6 "a"
+      Comma@6..7 ","
+      RParen@7..8 ")"
+      Whitespace@8..9 " "
+    BlockExpr@9..11
+      LBrace@9..10 "{"
+      RBrace@10..11 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_multiple_statements() {
+        check(
+            "fn f(x) {\n  let y = x * 2\n  y - 1\n}",
+            expect![[r#"
+Root@0..35
+  FnDef@0..35
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..8
+      LParen@4..5 "("
+      Param@5..6
+        Ident@5..6 "x"
+      RParen@6..7 ")"
+      Whitespace@7..8 " "
+    BlockExpr@8..35
+      LBrace@8..9 "{"
+      Whitespace@9..12 "\n  "
+      VariableDef@12..28
+        LetKw@12..15 "let"
+        Whitespace@15..16 " "
+        Ident@16..17 "y"
+        Whitespace@17..18 " "
+        Equals@18..19 "="
+        Whitespace@19..20 " "
+        InfixExpr@20..28
+          VariableRef@20..22
+            Ident@20..21 "x"
+            Whitespace@21..22 " "
+          Star@22..23 "*"
+          Whitespace@23..24 " "
+          Literal@24..28
+            Number@24..25 "2"
+            Whitespace@25..28 "\n  "
+      InfixExpr@28..34
+        VariableRef@28..30
+          Ident@28..29 "y"
+          Whitespace@29..30 " "
+        Minus@30..31 "-"
+        Whitespace@31..32 " "
+        Literal@32..34
+          Number@32..33 "1"
+          Whitespace@33..34 "\n"
+      RBrace@34..35 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_fn_def() {
+        check(
+            "fn outer() { fn inner() {} }",
+            expect![[r#"
+Root@0..28
+  FnDef@0..28
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..8 "outer"
+    ParamList@8..11
+      LParen@8..9 "("
+      RParen@9..10 ")"
+      Whitespace@10..11 " "
+    BlockExpr@11..28
+      LBrace@11..12 "{"
+      Whitespace@12..13 " "
+      FnDef@13..27
+        FnKw@13..15 "fn"
+        Whitespace@15..16 " "
+        Ident@16..21 "inner"
+        ParamList@21..24
+          LParen@21..22 "("
+          RParen@22..23 ")"
+          Whitespace@23..24 " "
+        BlockExpr@24..27
+          LBrace@24..25 "{"
+          RBrace@25..26 "}"
+          Whitespace@26..27 " "
+      RBrace@27..28 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_without_name_as_closure() {
+        check(
+            "fn (a) {}",
+            expect![[r#"
+Root@0..9
+  ClosureExpr@0..9
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    ParamList@3..7
+      LParen@3..4 "("
+      Param@4..5
+        Ident@4..5 "a"
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..9
+      LBrace@7..8 "{"
+      RBrace@8..9 "}"
+error at 7..8: expected ‘=>’, but found ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_invalid_param() {
+        check(
+            "fn f(1) {}",
+            expect![[r#"
+Root@0..10
+  FnDef@0..10
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..8
+      LParen@4..5 "("
+      Error@5..6
+        Number@5..6 "1"
+      RParen@6..7 ")"
+      Whitespace@7..8 " "
+    BlockExpr@8..10
+      LBrace@8..9 "{"
+      RBrace@9..10 "}"
+error at 5..6: expected ‘)’ or identifier, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_missing_comma_between_params() {
+        check(
+            "fn f(a b) {}",
+            expect![[r#"
+Root@0..12
+  FnDef@0..12
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..10
+      LParen@4..5 "("
+      Param@5..7
+        Ident@5..6 "a"
+        Whitespace@6..7 " "
+      Error@7..8
+        Ident@7..8 "b"
+      RParen@8..9 ")"
+      Whitespace@9..10 " "
+    BlockExpr@10..12
+      LBrace@10..11 "{"
+      RBrace@11..12 "}"
+error at 7..8: expected ‘:’, ‘)’ or ‘,’, but found identifier"#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_fn_body() {
+        check(
+            "fn f() {\n  let a = 1",
+            expect![[r#"
+Root@0..20
+  FnDef@0..20
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..20
+      LBrace@7..8 "{"
+      Whitespace@8..11 "\n  "
+      VariableDef@11..20
+        LetKw@11..14 "let"
+        Whitespace@14..15 " "
+        Ident@15..16 "a"
+        Whitespace@16..17 " "
+        Equals@17..18 "="
+        Whitespace@18..19 " "
+        Literal@19..20
+          Number@19..20 "1"
+error at 19..20: expected ‘+’, ‘-’, ‘*’, ‘/’, ‘==’, ‘!=’, ‘<’, ‘<=’, ‘>’, ‘>=’, ‘&&’, ‘||’, ‘=’, ‘+=’, ‘-=’, ‘*=’, ‘/=’, ‘(’, ‘[’, ‘.’, ‘;’ or ‘}’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_variable_def_without_name() {
+        check(
+            "let = 1",
+            expect![[r#"
+Root@0..7
+  VariableDef@0..7
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Equals@4..5 "="
+    Whitespace@5..6 " "
+    Literal@6..7
+      Number@6..7 "1"
+error at 4..5: expected identifier, but found ‘=’"#]],
+        );
+    }
+
+    #[test]
+    fn recover_in_param_list() {
+        check(
+            "fn f(a, 1, b) { a }",
+            expect![[r#"
+Root@0..19
+  FnDef@0..19
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..14
+      LParen@4..5 "("
+      Param@5..6
+        Ident@5..6 "a"
+      Comma@6..7 ","
+      Whitespace@7..8 " "
+      Error@8..9
+        Number@8..9 "1"
+      Comma@9..10 ","
+      Whitespace@10..11 " "
+      Param@11..12
+        Ident@11..12 "b"
+      RParen@12..13 ")"
+      Whitespace@13..14 " "
+    BlockExpr@14..19
+      LBrace@14..15 "{"
+      Whitespace@15..16 " "
+      VariableRef@16..18
+        Ident@16..17 "a"
+        Whitespace@17..18 " "
+      RBrace@18..19 "}"
+error at 8..9: expected ‘)’ or identifier, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn recover_at_fn_body_after_unclosed_param_list() {
+        check(
+            "fn f(a { a }",
+            expect![[r#"
+Root@0..12
+  FnDef@0..12
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      Param@5..7
+        Ident@5..6 "a"
+        Whitespace@6..7 " "
+    BlockExpr@7..12
+      LBrace@7..8 "{"
+      Whitespace@8..9 " "
+      VariableRef@9..11
+        Ident@9..10 "a"
+        Whitespace@10..11 " "
+      RBrace@11..12 "}"
+error at 7..8: expected ‘:’, ‘)’ or ‘,’, but found ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_defs_nested_too_deeply() {
+        check_with_max_depth(
+            "fn f() { fn g() { fn h() { 1 } } }\nlet a = 1",
+            3,
+            expect![[r#"
+Root@0..44
+  FnDef@0..35
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    BlockExpr@7..35
+      LBrace@7..8 "{"
+      Whitespace@8..9 " "
+      FnDef@9..33
+        FnKw@9..11 "fn"
+        Whitespace@11..12 " "
+        Ident@12..13 "g"
+        ParamList@13..16
+          LParen@13..14 "("
+          RParen@14..15 ")"
+          Whitespace@15..16 " "
+        BlockExpr@16..33
+          LBrace@16..17 "{"
+          Whitespace@17..18 " "
+          FnDef@18..31
+            FnKw@18..20 "fn"
+            Whitespace@20..21 " "
+            Ident@21..22 "h"
+            ParamList@22..25
+              LParen@22..23 "("
+              RParen@23..24 ")"
+              Whitespace@24..25 " "
+            BlockExpr@25..31
+              LBrace@25..26 "{"
+              Whitespace@26..27 " "
+              Error@27..29
+                Number@27..28 "1"
+                Whitespace@28..29 " "
+              RBrace@29..30 "}"
+              Whitespace@30..31 " "
+          RBrace@31..32 "}"
+          Whitespace@32..33 " "
+      RBrace@33..34 "}"
+      Whitespace@34..35 "\n"
+  VariableDef@35..44
+    LetKw@35..38 "let"
+    Whitespace@38..39 " "
+    Ident@39..40 "a"
+    Whitespace@40..41 " "
+    Equals@41..42 "="
+    Whitespace@42..43 " "
+    Literal@43..44
+      Number@43..44 "1"
+error at 27..28: expression nested too deeply"#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_def() {
+        check(
+            "struct Point { x: Int, y: Int }",
+            expect![[r#"
+Root@0..31
+  StructDef@0..31
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    Ident@7..12 "Point"
+    Whitespace@12..13 " "
+    FieldDefList@13..31
+      LBrace@13..14 "{"
+      Whitespace@14..15 " "
+      FieldDef@15..21
+        Ident@15..16 "x"
+        Colon@16..17 ":"
+        Whitespace@17..18 " "
+        TypeRef@18..21
+          Ident@18..21 "Int"
+      Comma@21..22 ","
+      Whitespace@22..23 " "
+      FieldDef@23..30
+        Ident@23..24 "y"
+        Colon@24..25 ":"
+        Whitespace@25..26 " "
+        TypeRef@26..30
+          Ident@26..29 "Int"
+          Whitespace@29..30 " "
+      RBrace@30..31 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_struct_def() {
+        check(
+            "struct Unit {}",
+            expect![[r#"
+Root@0..14
+  StructDef@0..14
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    Ident@7..11 "Unit"
+    Whitespace@11..12 " "
+    FieldDefList@12..14
+      LBrace@12..13 "{"
+      RBrace@13..14 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_def_over_multiple_lines() {
+        check(
+            "struct Point {\n  x: Int,\n  y: Int,\n}\nlet a = 1",
+            expect![[r#"
+Root@0..46
+  StructDef@0..37
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    Ident@7..12 "Point"
+    Whitespace@12..13 " "
+    FieldDefList@13..37
+      LBrace@13..14 "{"
+      Whitespace@14..17 "\n  "
+      FieldDef@17..23
+        Ident@17..18 "x"
+        Colon@18..19 ":"
+        Whitespace@19..20 " "
+        TypeRef@20..23
+          Ident@20..23 "Int"
+      Comma@23..24 ","
+      Whitespace@24..27 "\n  "
+      FieldDef@27..33
+        Ident@27..28 "y"
+        Colon@28..29 ":"
+        Whitespace@29..30 " "
+        TypeRef@30..33
+          Ident@30..33 "Int"
+      Comma@33..34 ","
+      Whitespace@34..35 "\n"
+      RBrace@35..36 "}"
+      Whitespace@36..37 "\n"
+  VariableDef@37..46
+    LetKw@37..40 "let"
+    Whitespace@40..41 " "
+    Ident@41..42 "a"
+    Whitespace@42..43 " "
+    Equals@43..44 "="
+    Whitespace@44..45 " "
+    Literal@45..46
+      Number@45..46 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_def_with_doc_comment() {
+        check(
+            "## A point.\nstruct Point { x: Int }",
+            expect![[r###"
+Root@0..35
+  StructDef@0..35
+    DocComment@0..11 "## A point."
+    Whitespace@11..12 "\n"
+    StructKw@12..18 "struct"
+    Whitespace@18..19 " "
+    Ident@19..24 "Point"
+    Whitespace@24..25 " "
+    FieldDefList@25..35
+      LBrace@25..26 "{"
+      Whitespace@26..27 " "
+      FieldDef@27..34
+        Ident@27..28 "x"
+        Colon@28..29 ":"
+        Whitespace@29..30 " "
+        TypeRef@30..34
+          Ident@30..33 "Int"
+          Whitespace@33..34 " "
+      RBrace@34..35 "}""###]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_def_without_name() {
+        check(
+            "struct { x: Int }",
+            expect![[r#"
+Root@0..17
+  StructDef@0..17
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    FieldDefList@7..17
+      LBrace@7..8 "{"
+      Whitespace@8..9 " "
+      FieldDef@9..16
+        Ident@9..10 "x"
+        Colon@10..11 ":"
+        Whitespace@11..12 " "
+        TypeRef@12..16
+          Ident@12..15 "Int"
+          Whitespace@15..16 " "
+      RBrace@16..17 "}"
+error at 7..8: expected identifier, but found ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_def_without_fields() {
+        check(
+            "struct Point\nlet a = 1",
+            expect![[r#"
+Root@0..22
+  StructDef@0..13
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    Ident@7..12 "Point"
+    Whitespace@12..13 "\n"
+  VariableDef@13..22
+    LetKw@13..16 "let"
+    Whitespace@16..17 " "
+    Ident@17..18 "a"
+    Whitespace@18..19 " "
+    Equals@19..20 "="
+    Whitespace@20..21 " "
+    Literal@21..22
+      Number@21..22 "1"
+error at 13..16: expected ‘{’, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_field_def_without_type() {
+        check(
+            "struct Point { x, y: Int }",
+            expect![[r#"
+Root@0..26
+  StructDef@0..26
+    StructKw@0..6 "struct"
+    Whitespace@6..7 " "
+    Ident@7..12 "Point"
+    Whitespace@12..13 " "
+    FieldDefList@13..26
+      LBrace@13..14 "{"
+      Whitespace@14..15 " "
+      FieldDef@15..16
+        Ident@15..16 "x"
+      Comma@16..17 ","
+      Whitespace@17..18 " "
+      FieldDef@18..25
+        Ident@18..19 "y"
+        Colon@19..20 ":"
+        Whitespace@20..21 " "
+        TypeRef@21..25
+          Ident@21..24 "Int"
+          Whitespace@24..25 " "
+      RBrace@25..26 "}"
+error at 16..17: expected ‘:’, but found ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_def() {
+        check(
+            "enum Shape { Circle(r), Rect(w: Float, h: Float), Empty }",
+            expect![[r#"
+Root@0..57
+  EnumDef@0..57
+    EnumKw@0..4 "enum"
+    Whitespace@4..5 " "
+    Ident@5..10 "Shape"
+    Whitespace@10..11 " "
+    VariantList@11..57
+      LBrace@11..12 "{"
+      Whitespace@12..13 " "
+      Variant@13..22
+        Ident@13..19 "Circle"
+        ParamList@19..22
+          LParen@19..20 "("
+          Param@20..21
+            Ident@20..21 "r"
+          RParen@21..22 ")"
+      Comma@22..23 ","
+      Whitespace@23..24 " "
+      Variant@24..48
+        Ident@24..28 "Rect"
+        ParamList@28..48
+          LParen@28..29 "("
+          Param@29..37
+            Ident@29..30 "w"
+            Colon@30..31 ":"
+            Whitespace@31..32 " "
+            TypeRef@32..37
+              Ident@32..37 "Float"
+          Comma@37..38 ","
+          Whitespace@38..39 " "
+          Param@39..47
+            Ident@39..40 "h"
+            Colon@40..41 ":"
+            Whitespace@41..42 " "
+            TypeRef@42..47
+              Ident@42..47 "Float"
+          RParen@47..48 ")"
+      Comma@48..49 ","
+      Whitespace@49..50 " "
+      Variant@50..56
+        Ident@50..55 "Empty"
+        Whitespace@55..56 " "
+      RBrace@56..57 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_def_over_multiple_lines() {
+        check(
+            "enum Shape {\n  Circle(r),\n  Empty,\n}\nlet a = 1",
+            expect![[r#"
+Root@0..46
+  EnumDef@0..37
+    EnumKw@0..4 "enum"
+    Whitespace@4..5 " "
+    Ident@5..10 "Shape"
+    Whitespace@10..11 " "
+    VariantList@11..37
+      LBrace@11..12 "{"
+      Whitespace@12..15 "\n  "
+      Variant@15..24
+        Ident@15..21 "Circle"
+        ParamList@21..24
+          LParen@21..22 "("
+          Param@22..23
+            Ident@22..23 "r"
+          RParen@23..24 ")"
+      Comma@24..25 ","
+      Whitespace@25..28 "\n  "
+      Variant@28..33
+        Ident@28..33 "Empty"
+      Comma@33..34 ","
+      Whitespace@34..35 "\n"
+      RBrace@35..36 "}"
+      Whitespace@36..37 "\n"
+  VariableDef@37..46
+    LetKw@37..40 "let"
+    Whitespace@40..41 " "
+    Ident@41..42 "a"
+    Whitespace@42..43 " "
+    Equals@43..44 "="
+    Whitespace@44..45 " "
+    Literal@45..46
+      Number@45..46 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_def_with_doc_comment() {
+        check(
+            "## A shape.\nenum Shape { Empty }",
+            expect![[r###"
+Root@0..32
+  EnumDef@0..32
+    DocComment@0..11 "## A shape."
+    Whitespace@11..12 "\n"
+    EnumKw@12..16 "enum"
+    Whitespace@16..17 " "
+    Ident@17..22 "Shape"
+    Whitespace@22..23 " "
+    VariantList@23..32
+      LBrace@23..24 "{"
+      Whitespace@24..25 " "
+      Variant@25..31
+        Ident@25..30 "Empty"
+        Whitespace@30..31 " "
+      RBrace@31..32 "}""###]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_def_without_name() {
+        check(
+            "enum { A }",
+            expect![[r#"
+Root@0..10
+  EnumDef@0..10
+    EnumKw@0..4 "enum"
+    Whitespace@4..5 " "
+    VariantList@5..10
+      LBrace@5..6 "{"
+      Whitespace@6..7 " "
+      Variant@7..9
+        Ident@7..8 "A"
+        Whitespace@8..9 " "
+      RBrace@9..10 "}"
+error at 5..6: expected identifier, but found ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_def_with_invalid_variant() {
+        check(
+            "enum Shape { 1, A }",
+            expect![[r#"
+Root@0..19
+  EnumDef@0..19
+    EnumKw@0..4 "enum"
+    Whitespace@4..5 " "
+    Ident@5..10 "Shape"
+    Whitespace@10..11 " "
+    VariantList@11..19
+      LBrace@11..12 "{"
+      Whitespace@12..13 " "
+      Error@13..14
+        Number@13..14 "1"
+      Comma@14..15 ","
+      Whitespace@15..16 " "
+      Variant@16..18
+        Ident@16..17 "A"
+        Whitespace@17..18 " "
+      RBrace@18..19 "}"
+error at 13..14: expected ‘}’ or identifier, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_mod_def() {
+        check(
+            "mod shapes",
+            expect![[r#"
+Root@0..10
+  ModDef@0..10
+    ModKw@0..3 "mod"
+    Whitespace@3..4 " "
+    Ident@4..10 "shapes""#]],
+        );
+    }
+
+    #[test]
+    fn parse_mod_defs_with_semicolons() {
+        check(
+            "mod a; mod b;",
+            expect![[r#"
+Root@0..13
+  ModDef@0..5
+    ModKw@0..3 "mod"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+  Semicolon@5..6 ";"
+  Whitespace@6..7 " "
+  ModDef@7..12
+    ModKw@7..10 "mod"
+    Whitespace@10..11 " "
+    Ident@11..12 "b"
+  Semicolon@12..13 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_mod_def_with_doc_comment() {
+        check(
+            "## Shapes and their areas.\nmod shapes",
+            expect![[r###"
+Root@0..37
+  ModDef@0..37
+    DocComment@0..26 "## Shapes and their a ..."
+    Whitespace@26..27 "\n"
+    ModKw@27..30 "mod"
+    Whitespace@30..31 " "
+    Ident@31..37 "shapes""###]],
+        );
+    }
+
+    #[test]
+    fn parse_mod_def_without_name() {
+        check(
+            "mod\nlet a = 1",
+            expect![[r#"
+Root@0..13
+  ModDef@0..4
+    ModKw@0..3 "mod"
+    Whitespace@3..4 "\n"
+  VariableDef@4..13
+    LetKw@4..7 "let"
+    Whitespace@7..8 " "
+    Ident@8..9 "a"
+    Whitespace@9..10 " "
+    Equals@10..11 "="
+    Whitespace@11..12 " "
+    Literal@12..13
+      Number@12..13 "1"
+error at 4..7: expected identifier, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_use_def() {
+        check(
+            "use shapes::area",
+            expect![[r#"
+Root@0..16
+  UseDef@0..16
+    UseKw@0..3 "use"
+    Whitespace@3..4 " "
+    Path@4..16
+      Ident@4..10 "shapes"
+      ColonColon@10..12 "::"
+      Ident@12..16 "area""#]],
+        );
+    }
+
+    #[test]
+    fn parse_use_def_without_path() {
+        check(
+            "use\nlet a = 1",
+            expect![[r#"
+Root@0..13
+  UseDef@0..4
+    UseKw@0..3 "use"
+    Whitespace@3..4 "\n"
+  VariableDef@4..13
+    LetKw@4..7 "let"
+    Whitespace@7..8 " "
+    Ident@8..9 "a"
+    Whitespace@9..10 " "
+    Equals@10..11 "="
+    Whitespace@11..12 " "
+    Literal@12..13
+      Number@12..13 "1"
+error at 4..7: expected identifier, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_use_def_with_missing_segment() {
+        check(
+            "use a::\nlet b = 1",
+            expect![[r#"
+Root@0..17
+  UseDef@0..8
+    UseKw@0..3 "use"
+    Whitespace@3..4 " "
+    Path@4..8
+      Ident@4..5 "a"
+      ColonColon@5..7 "::"
+      Whitespace@7..8 "\n"
+  VariableDef@8..17
+    LetKw@8..11 "let"
+    Whitespace@11..12 " "
+    Ident@12..13 "b"
+    Whitespace@13..14 " "
+    Equals@14..15 "="
+    Whitespace@15..16 " "
+    Literal@16..17
+      Number@16..17 "1"
+error at 8..11: expected identifier, but found ‘let’"#]],
         );
     }
 }
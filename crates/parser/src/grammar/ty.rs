@@ -0,0 +1,361 @@
+use super::*;
+
+/// Parses a type, which is either a name, e.g. `Int`, a path to a type in another module, e.g.
+/// `shapes::Shape`, a tuple type, e.g. `(Int, Bool)`, or a type in parentheses, e.g. `(Int)`.
+pub(super) fn ty(p: &mut Parser) -> Option<CompletedMarker> {
+    if p.at(TokenKind::Ident) {
+        let m = p.start();
+        let path_m = p.start();
+        p.bump();
+
+        // Like in expressions, a name only becomes a path if it’s followed by `::`.
+        if p.at(TokenKind::ColonColon) {
+            expr::path_segments(p);
+            path_m.complete(p, SyntaxKind::Path);
+        } else {
+            path_m.abandon();
+        }
+
+        Some(m.complete(p, SyntaxKind::TypeRef))
+    } else if p.at(TokenKind::LParen) {
+        // Types in parentheses can nest without going through an expression.
+        if !p.enter() {
+            return None;
+        }
+
+        let cm = paren_or_tuple_type(p);
+        p.exit();
+        Some(cm)
+    } else {
+        p.error();
+        None
+    }
+}
+
+/// Parses either a type in parentheses, e.g. `(Int)`, or a tuple type, e.g. `(Int, Bool)`. Like
+/// with tuple expressions, a comma makes it a tuple type, so a tuple type with one field needs a
+/// trailing comma, e.g. `(Int,)`; `()` is the empty tuple type. Both are `TypeRef`s, which
+/// `ast::TypeRef::kind` tells apart the same way.
+fn paren_or_tuple_type(p: &mut Parser) -> CompletedMarker {
+    assert!(p.at(TokenKind::LParen));
+
+    let m = p.start();
+    p.bump();
+
+    delimited(p, TokenKind::RParen, ty);
+
+    m.complete(p, SyntaxKind::TypeRef)
+}
+
+/// Parses the `: Type` annotating a binding or parameter, if there is one.
+pub(super) fn ascription(p: &mut Parser) {
+    if p.at(TokenKind::Colon) {
+        p.bump();
+        ty(p);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check;
+    use expect_test::expect;
+
+    #[test]
+    fn parse_variable_def_with_type() {
+        check(
+            "let count: Int = 0",
+            expect![[r#"
+Root@0..18
+  VariableDef@0..18
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..9 "count"
+    Colon@9..10 ":"
+    Whitespace@10..11 " "
+    TypeRef@11..15
+      Ident@11..14 "Int"
+      Whitespace@14..15 " "
+    Equals@15..16 "="
+    Whitespace@16..17 " "
+    Literal@17..18
+      Number@17..18 "0""#]],
+        );
+    }
+
+    #[test]
+    fn parse_variable_def_with_path_type() {
+        check(
+            "let s: shapes::Shape = x",
+            expect![[r#"
+Root@0..24
+  VariableDef@0..24
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "s"
+    Colon@5..6 ":"
+    Whitespace@6..7 " "
+    TypeRef@7..21
+      Path@7..21
+        Ident@7..13 "shapes"
+        ColonColon@13..15 "::"
+        Ident@15..20 "Shape"
+        Whitespace@20..21 " "
+    Equals@21..22 "="
+    Whitespace@22..23 " "
+    VariableRef@23..24
+      Ident@23..24 "x""#]],
+        );
+    }
+
+    #[test]
+    fn parse_path_type_with_missing_segment() {
+        check(
+            "fn f(s: shapes::) {}",
+            expect![[r#"
+Root@0..20
+  FnDef@0..20
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..18
+      LParen@4..5 "("
+      Param@5..16
+        Ident@5..6 "s"
+        Colon@6..7 ":"
+        Whitespace@7..8 " "
+        TypeRef@8..16
+          Path@8..16
+            Ident@8..14 "shapes"
+            ColonColon@14..16 "::"
+      RParen@16..17 ")"
+      Whitespace@17..18 " "
+    BlockExpr@18..20
+      LBrace@18..19 "{"
+      RBrace@19..20 "}"
+error at 16..17: expected identifier, but found ‘)’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_variable_def_with_missing_type() {
+        check(
+            "let a: = 1",
+            expect![[r#"
+Root@0..10
+  VariableDef@0..10
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Colon@5..6 ":"
+    Whitespace@6..7 " "
+    Equals@7..8 "="
+    Whitespace@8..9 " "
+    Literal@9..10
+      Number@9..10 "1"
+error at 7..8: expected identifier or ‘(’, but found ‘=’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_types() {
+        check(
+            "fn add(a: Int, b: Int) -> Int { a + b }",
+            expect![[r#"
+Root@0..39
+  FnDef@0..39
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..6 "add"
+    ParamList@6..23
+      LParen@6..7 "("
+      Param@7..13
+        Ident@7..8 "a"
+        Colon@8..9 ":"
+        Whitespace@9..10 " "
+        TypeRef@10..13
+          Ident@10..13 "Int"
+      Comma@13..14 ","
+      Whitespace@14..15 " "
+      Param@15..21
+        Ident@15..16 "b"
+        Colon@16..17 ":"
+        Whitespace@17..18 " "
+        TypeRef@18..21
+          Ident@18..21 "Int"
+      RParen@21..22 ")"
+      Whitespace@22..23 " "
+    Arrow@23..25 "->"
+    Whitespace@25..26 " "
+    TypeRef@26..30
+      Ident@26..29 "Int"
+      Whitespace@29..30 " "
+    BlockExpr@30..39
+      LBrace@30..31 "{"
+      Whitespace@31..32 " "
+      InfixExpr@32..38
+        VariableRef@32..34
+          Ident@32..33 "a"
+          Whitespace@33..34 " "
+        Plus@34..35 "+"
+        Whitespace@35..36 " "
+        VariableRef@36..38
+          Ident@36..37 "b"
+          Whitespace@37..38 " "
+      RBrace@38..39 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_def_with_missing_return_type() {
+        check(
+            "fn f() -> { 1 }",
+            expect![[r#"
+Root@0..15
+  FnDef@0..15
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..7
+      LParen@4..5 "("
+      RParen@5..6 ")"
+      Whitespace@6..7 " "
+    Arrow@7..9 "->"
+    Whitespace@9..10 " "
+    BlockExpr@10..15
+      LBrace@10..11 "{"
+      Whitespace@11..12 " "
+      Literal@12..14
+        Number@12..13 "1"
+        Whitespace@13..14 " "
+      RBrace@14..15 "}"
+error at 10..11: expected identifier or ‘(’, but found ‘{’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_param_with_missing_type() {
+        check(
+            "fn f(a:, b: Int) {}",
+            expect![[r#"
+Root@0..19
+  FnDef@0..19
+    FnKw@0..2 "fn"
+    Whitespace@2..3 " "
+    Ident@3..4 "f"
+    ParamList@4..17
+      LParen@4..5 "("
+      Param@5..7
+        Ident@5..6 "a"
+        Colon@6..7 ":"
+      Comma@7..8 ","
+      Whitespace@8..9 " "
+      Param@9..15
+        Ident@9..10 "b"
+        Colon@10..11 ":"
+        Whitespace@11..12 " "
+        TypeRef@12..15
+          Ident@12..15 "Int"
+      RParen@15..16 ")"
+      Whitespace@16..17 " "
+    BlockExpr@17..19
+      LBrace@17..18 "{"
+      RBrace@18..19 "}"
+error at 7..8: expected identifier or ‘(’, but found ‘,’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuple_types() {
+        check(
+            "let a: ((Int,), (), (Int, Bool)) = b",
+            expect![[r#"
+Root@0..36
+  VariableDef@0..36
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Colon@5..6 ":"
+    Whitespace@6..7 " "
+    TypeRef@7..33
+      LParen@7..8 "("
+      TypeRef@8..14
+        LParen@8..9 "("
+        TypeRef@9..12
+          Ident@9..12 "Int"
+        Comma@12..13 ","
+        RParen@13..14 ")"
+      Comma@14..15 ","
+      Whitespace@15..16 " "
+      TypeRef@16..18
+        LParen@16..17 "("
+        RParen@17..18 ")"
+      Comma@18..19 ","
+      Whitespace@19..20 " "
+      TypeRef@20..31
+        LParen@20..21 "("
+        TypeRef@21..24
+          Ident@21..24 "Int"
+        Comma@24..25 ","
+        Whitespace@25..26 " "
+        TypeRef@26..30
+          Ident@26..30 "Bool"
+        RParen@30..31 ")"
+      RParen@31..32 ")"
+      Whitespace@32..33 " "
+    Equals@33..34 "="
+    Whitespace@34..35 " "
+    VariableRef@35..36
+      Ident@35..36 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_paren_type() {
+        check(
+            "let a: (Int) = b",
+            expect![[r#"
+Root@0..16
+  VariableDef@0..16
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Colon@5..6 ":"
+    Whitespace@6..7 " "
+    TypeRef@7..13
+      LParen@7..8 "("
+      TypeRef@8..11
+        Ident@8..11 "Int"
+      RParen@11..12 ")"
+      Whitespace@12..13 " "
+    Equals@13..14 "="
+    Whitespace@14..15 " "
+    VariableRef@15..16
+      Ident@15..16 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_tuple_type() {
+        check(
+            "let a: (Int = 1",
+            expect![[r#"
+Root@0..15
+  VariableDef@0..15
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Colon@5..6 ":"
+    Whitespace@6..7 " "
+    TypeRef@7..12
+      LParen@7..8 "("
+      TypeRef@8..12
+        Ident@8..11 "Int"
+        Whitespace@11..12 " "
+    Equals@12..13 "="
+    Whitespace@13..14 " "
+    Literal@14..15
+      Number@14..15 "1"
+error at 12..13: expected ‘::’, ‘)’ or ‘,’, but found ‘=’"#]],
+        );
+    }
+}
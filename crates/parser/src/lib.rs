@@ -6,19 +6,80 @@ mod parser;
 mod sink;
 mod source;
 
-use crate::parser::{ParseError, Parser};
+pub use crate::parser::{ParseError, ParseErrorKind};
+
+use crate::parser::Parser;
 use lexer::Lexer;
 use rowan::GreenNode;
 use sink::Sink;
 use source::Source;
 use syntax::SyntaxNode;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseConfig {
+    /// How deeply expressions and blocks may be nested. Anything nested deeper is reported as an
+    /// error and left out of the syntax tree, which bounds the depth of the tree and so how deeply
+    /// anything walking it recursively has to recurse.
+    pub max_depth: u32,
+}
+
+impl Default for ParseConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { max_depth: 256 }
+    }
+}
+
+#[inline]
 pub fn parse(input: &str) -> Parse {
-    let tokens: Vec<_> = Lexer::new(input).collect();
+    parse_with_config(input, ParseConfig::default())
+}
+
+pub fn parse_with_config(input: &str, config: ParseConfig) -> Parse {
+    parse_entry_point(input, config, grammar::root)
+}
+
+/// Parses a single expression, e.g. `a + 1`, so that the root of the tree is the expression’s
+/// node. Anything after the expression is an error, as is an empty input, in which case the root
+/// is an error node instead.
+#[inline]
+pub fn parse_expr(input: &str) -> Parse {
+    parse_expr_with_config(input, ParseConfig::default())
+}
+
+pub fn parse_expr_with_config(input: &str, config: ParseConfig) -> Parse {
+    parse_entry_point(input, config, grammar::expr_fragment)
+}
+
+/// Parses a single statement, e.g. `let a = 1`, like `parse_expr` does expressions.
+#[inline]
+pub fn parse_stmt(input: &str) -> Parse {
+    parse_stmt_with_config(input, ParseConfig::default())
+}
+
+pub fn parse_stmt_with_config(input: &str, config: ParseConfig) -> Parse {
+    parse_entry_point(input, config, grammar::stmt_fragment)
+}
+
+/// Parses a single type, e.g. `Int`, like `parse_expr` does expressions.
+#[inline]
+pub fn parse_type(input: &str) -> Parse {
+    parse_type_with_config(input, ParseConfig::default())
+}
+
+pub fn parse_type_with_config(input: &str, config: ParseConfig) -> Parse {
+    parse_entry_point(input, config, grammar::ty_fragment)
+}
+
+fn parse_entry_point(input: &str, config: ParseConfig, entry_point: fn(&mut Parser)) -> Parse {
+    let mut lexer = Lexer::new(input);
+    let tokens: Vec<_> = lexer.by_ref().collect();
+    let lex_errors = lexer.into_errors();
+
     let source = Source::new(&tokens);
-    let parser = Parser::new(source);
-    let events = parser.parse();
-    let sink = Sink::new(&tokens, events);
+    let parser = Parser::new(source, config.max_depth);
+    let events = parser.parse(entry_point);
+    let sink = Sink::new(&tokens, events, lex_errors);
     sink.finish()
 }
 
@@ -44,6 +105,12 @@ impl Parse {
     pub fn syntax(&self) -> SyntaxNode {
         SyntaxNode::new_root(self.green_node.clone())
     }
+
+    /// Returns the lex and parse errors, sorted by where they start.
+    #[inline]
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
 }
 
 #[cfg(test)]
@@ -51,3 +118,302 @@ fn check(input: &str, expected_tree: expect_test::Expect) {
     let parse = parse(input);
     expected_tree.assert_eq(&parse.debug_tree());
 }
+
+#[cfg(test)]
+fn check_with_max_depth(input: &str, max_depth: u32, expected_tree: expect_test::Expect) {
+    let parse = parse_with_config(input, ParseConfig { max_depth });
+    expected_tree.assert_eq(&parse.debug_tree());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expect_test::{Expect, expect};
+    use lexer::{LexErrorKind, TokenKind};
+    use text_size::TextRange;
+
+    fn check_expr(input: &str, expected_tree: Expect) {
+        expected_tree.assert_eq(&parse_expr(input).debug_tree());
+    }
+
+    fn check_stmt(input: &str, expected_tree: Expect) {
+        expected_tree.assert_eq(&parse_stmt(input).debug_tree());
+    }
+
+    fn check_type(input: &str, expected_tree: Expect) {
+        expected_tree.assert_eq(&parse_type(input).debug_tree());
+    }
+
+    #[test]
+    fn errors() {
+        let parse = parse("a + $\nlet b =");
+        let errors: Vec<_> = parse
+            .errors()
+            .iter()
+            .map(|error| (error.code(), error.kind().clone(), error.range()))
+            .collect();
+
+        assert_eq!(
+            errors,
+            [
+                (
+                    "L0001",
+                    ParseErrorKind::Lex(LexErrorKind::InvalidCharacter('$')),
+                    TextRange::new(4.into(), 5.into()),
+                ),
+                (
+                    "P0001",
+                    ParseErrorKind::Unexpected {
+                        expected: vec![
+                            TokenKind::Number,
+                            TokenKind::Float,
+                            TokenKind::String,
+                            TokenKind::TrueKw,
+                            TokenKind::FalseKw,
+                            TokenKind::Ident,
+                            TokenKind::Minus,
+                            TokenKind::Bang,
+                            TokenKind::LParen,
+                            TokenKind::LBrace,
+                            TokenKind::LBracket,
+                            TokenKind::IfKw,
+                            TokenKind::MatchKw,
+                            TokenKind::WhileKw,
+                            TokenKind::LoopKw,
+                            TokenKind::BreakKw,
+                            TokenKind::ContinueKw,
+                            TokenKind::Pipe,
+                            TokenKind::OrOr,
+                            TokenKind::FnKw,
+                        ],
+                        found: None,
+                    },
+                    TextRange::new(12.into(), 13.into()),
+                ),
+            ],
+        );
+    }
+
+    fn check_nested_too_deeply(input: &str) {
+        let codes: Vec<_> = parse(input).errors().iter().map(ParseError::code).collect();
+        assert_eq!(codes, ["P0002"]);
+    }
+
+    #[test]
+    fn deeply_nested_parens() {
+        let input = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+        check_nested_too_deeply(&input);
+    }
+
+    #[test]
+    fn deeply_nested_prefix_exprs() {
+        check_nested_too_deeply(&format!("{}1", "-".repeat(100_000)));
+    }
+
+    #[test]
+    fn long_binary_expr_chain() {
+        check_nested_too_deeply(&format!("1{}", " + 1".repeat(100_000)));
+    }
+
+    #[test]
+    fn deeply_nested_arrays() {
+        let input = format!("{}1{}", "[".repeat(100_000), "]".repeat(100_000));
+        check_nested_too_deeply(&input);
+    }
+
+    #[test]
+    fn deeply_nested_tuple_types() {
+        let input = format!(
+            "let a: {}Int{} = 1",
+            "(".repeat(100_000),
+            ",)".repeat(100_000)
+        );
+        check_nested_too_deeply(&input);
+    }
+
+    #[test]
+    fn deeply_nested_pats() {
+        let input = format!(
+            "match a {{ {}b{} => c }}",
+            "Some(".repeat(100_000),
+            ")".repeat(100_000)
+        );
+        check_nested_too_deeply(&input);
+    }
+
+    #[test]
+    fn deeply_nested_blocks() {
+        let input = format!("{}{}", "fn f() {".repeat(100_000), "}".repeat(100_000));
+        check_nested_too_deeply(&input);
+    }
+
+    #[test]
+    fn deeply_nested_closures() {
+        check_nested_too_deeply(&format!("{}1", "fn(x) => ".repeat(1000)));
+    }
+
+    #[test]
+    fn no_errors() {
+        assert!(parse("let a = 1").errors().is_empty());
+    }
+
+    #[test]
+    fn parse_expr_fragment() {
+        check_expr(
+            " 1 + 2 ",
+            expect![[r#"
+InfixExpr@0..7
+  Whitespace@0..1 " "
+  Literal@1..3
+    Number@1..2 "1"
+    Whitespace@2..3 " "
+  Plus@3..4 "+"
+  Whitespace@4..5 " "
+  Literal@5..7
+    Number@5..6 "2"
+    Whitespace@6..7 " ""#]],
+        );
+    }
+
+    #[test]
+    fn parse_literal_fragment_with_leading_comment() {
+        check_expr(
+            "# one\n1",
+            expect![[r##"
+Literal@0..7
+  Comment@0..5 "# one"
+  Whitespace@5..6 "\n"
+  Number@6..7 "1""##]],
+        );
+    }
+
+    #[test]
+    fn parse_fragments_with_config() {
+        let config = ParseConfig { max_depth: 2 };
+
+        for parse in [
+            parse_expr_with_config("((1))", config),
+            parse_stmt_with_config("let a = ((1))", config),
+            parse_type_with_config("(((Int,),),)", config),
+        ] {
+            let codes: Vec<_> = parse.errors().iter().map(ParseError::code).collect();
+            assert_eq!(codes, ["P0002"]);
+        }
+    }
+
+    #[test]
+    fn parse_expr_fragment_with_trailing_input() {
+        check_expr(
+            "1 + 2 3 4",
+            expect![[r#"
+Error@0..9
+  InfixExpr@0..6
+    Literal@0..2
+      Number@0..1 "1"
+      Whitespace@1..2 " "
+    Plus@2..3 "+"
+    Whitespace@3..4 " "
+    Literal@4..6
+      Number@4..5 "2"
+      Whitespace@5..6 " "
+  Error@6..9
+    Number@6..7 "3"
+    Whitespace@7..8 " "
+    Number@8..9 "4"
+error at 6..7: expected end of input, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_invalid_expr_fragment() {
+        check_expr(
+            ") 1",
+            expect![[r#"
+Error@0..3
+  Error@0..2
+    RParen@0..1 ")"
+    Whitespace@1..2 " "
+  Error@2..3
+    Number@2..3 "1"
+error at 0..1: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’, but found ‘)’
+error at 2..3: expected end of input, but found number"#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_expr_fragment() {
+        check_expr(
+            "",
+            expect![[r#"
+Error@0..0
+error at 0..0: expected number, float, string, ‘true’, ‘false’, identifier, ‘-’, ‘!’, ‘(’, ‘{’, ‘[’, ‘if’, ‘match’, ‘while’, ‘loop’, ‘break’, ‘continue’, ‘|’, ‘||’ or ‘fn’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_stmt_fragment() {
+        check_stmt(
+            "let a = 1",
+            expect![[r#"
+VariableDef@0..9
+  LetKw@0..3 "let"
+  Whitespace@3..4 " "
+  Ident@4..5 "a"
+  Whitespace@5..6 " "
+  Equals@6..7 "="
+  Whitespace@7..8 " "
+  Literal@8..9
+    Number@8..9 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_stmt_fragment_with_trailing_stmt() {
+        check_stmt(
+            "let a = 1\nlet b = 2",
+            expect![[r#"
+Error@0..19
+  VariableDef@0..10
+    LetKw@0..3 "let"
+    Whitespace@3..4 " "
+    Ident@4..5 "a"
+    Whitespace@5..6 " "
+    Equals@6..7 "="
+    Whitespace@7..8 " "
+    Literal@8..10
+      Number@8..9 "1"
+      Whitespace@9..10 "\n"
+  Error@10..19
+    LetKw@10..13 "let"
+    Whitespace@13..14 " "
+    Ident@14..15 "b"
+    Whitespace@15..16 " "
+    Equals@16..17 "="
+    Whitespace@17..18 " "
+    Number@18..19 "2"
+error at 10..13: expected end of input, but found ‘let’"#]],
+        );
+    }
+
+    #[test]
+    fn parse_type_fragment() {
+        check_type(
+            "Int",
+            expect![[r#"
+TypeRef@0..3
+  Ident@0..3 "Int""#]],
+        );
+    }
+
+    #[test]
+    fn parse_invalid_type_fragment() {
+        check_type(
+            "1",
+            expect![[r#"
+Error@0..1
+  Error@0..1
+    Number@0..1 "1"
+error at 0..1: expected identifier or ‘(’, but found number"#]],
+        );
+    }
+}
@@ -1,7 +1,7 @@
 pub(crate) mod marker;
 
 mod parse_error;
-pub(crate) use parse_error::ParseError;
+pub use parse_error::{ParseError, ParseErrorKind};
 
 use crate::event::Event;
 use crate::grammar;
@@ -10,29 +10,56 @@ use lexer::{Token, TokenKind};
 use marker::Marker;
 use std::mem;
 use syntax::SyntaxKind;
+use text_size::TextRange;
 
-const RECOVERY_SET: &[TokenKind; 1] = &[TokenKind::LetKw];
+/// Tokens that error recovery never eats, wherever it happens, since they start new statements.
+const RECOVERY_SET: &[TokenKind] = &[
+    TokenKind::LetKw,
+    TokenKind::FnKw,
+    TokenKind::StructKw,
+    TokenKind::EnumKw,
+    TokenKind::ModKw,
+    TokenKind::UseKw,
+];
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Parser<'t, 'input> {
     source: Source<'t, 'input>,
     events: Vec<Event>,
     expected_kinds: Vec<TokenKind>,
+    /// the tokens of every pushed recovery set, innermost last
+    recovery_sets: Vec<TokenKind>,
+    /// how many tokens of `recovery_sets` there were before each set was pushed
+    recovery_set_starts: Vec<usize>,
+    last_error_range: Option<TextRange>,
+    num_errors: usize,
+    newlines_significant: bool,
+    records_allowed: bool,
+    depth: u32,
+    max_depth: u32,
 }
 
 impl<'t, 'input> Parser<'t, 'input> {
     #[inline]
-    pub(crate) fn new(source: Source<'t, 'input>) -> Self {
+    pub(crate) fn new(source: Source<'t, 'input>, max_depth: u32) -> Self {
         Self {
             source,
             events: Vec::new(),
             expected_kinds: Vec::new(),
+            recovery_sets: Vec::new(),
+            recovery_set_starts: Vec::new(),
+            last_error_range: None,
+            num_errors: 0,
+            newlines_significant: true,
+            records_allowed: true,
+            depth: 0,
+            max_depth,
         }
     }
 
     #[inline]
-    pub(crate) fn parse(mut self) -> Vec<Event> {
-        grammar::root(&mut self);
+    pub(crate) fn parse(mut self, entry_point: fn(&mut Self)) -> Vec<Event> {
+        entry_point(&mut self);
         self.events
     }
 
@@ -54,29 +81,230 @@ impl<'t, 'input> Parser<'t, 'input> {
     }
 
     pub(crate) fn error(&mut self) {
-        let current_token = self.source.peek_token();
+        self.num_errors += 1;
 
-        let (found, range) = if let Some(Token { kind, range, .. }) = current_token {
-            (Some(*kind), *range)
-        } else {
-            // If we’re at the end of the input we use the range of the very last token in the
-            // input.
-            (None, self.source.last_token_range().unwrap())
+        let (found, range) = self.current_token_for_error();
+
+        let expected = mem::take(&mut self.expected_kinds);
+
+        // The lexer has already reported why an unrecognized token is invalid, and if recovery
+        // left us at the same token as the last error then we’ve already reported this mistake,
+        // so in both cases there’s no need to pile on another error.
+        let is_reported = found == Some(TokenKind::Error) || self.last_error_range == Some(range);
+        self.last_error_range = Some(range);
+
+        if !is_reported {
+            self.events.push(Event::Error(ParseError {
+                kind: ParseErrorKind::Unexpected { expected, found },
+                range,
+            }));
+        }
+
+        if self.at_recovery_set() || self.at_end() {
+            return;
+        }
+
+        let m = self.start();
+        self.bump();
+
+        // The rest of the run of unexpected tokens goes in the same node, so that one mistake only
+        // produces one error. The run ends wherever parsing might be able to pick up again.
+        while !self.at_recovery_set() && !self.at_end() && !self.at_set(grammar::EXPR_FIRST) {
+            self.bump();
+        }
+
+        m.complete(self, SyntaxKind::Error);
+    }
+
+    /// Reports that the current token should have been on a new line or after the given
+    /// terminator, e.g. a `;`, since it follows a complete statement.
+    pub(crate) fn error_missing_terminator(&mut self, terminator: TokenKind) {
+        self.num_errors += 1;
+
+        let (found, range) = self.current_token_for_error();
+        self.expected_kinds.clear();
+        self.last_error_range = Some(range);
+
+        // Unrecognized tokens have already been reported by the lexer.
+        if let Some(found) = found.filter(|kind| *kind != TokenKind::Error) {
+            self.events.push(Event::Error(ParseError {
+                kind: ParseErrorKind::MissingTerminator { terminator, found },
+                range,
+            }));
+        }
+    }
+
+    /// Reports anything left in the input, putting it all in an error node, since the input should
+    /// have ended by now.
+    pub(crate) fn expect_end(&mut self) {
+        let Some(found) = self.peek() else {
+            return;
         };
 
-        self.events.push(Event::Error(ParseError {
-            expected: mem::take(&mut self.expected_kinds),
-            found,
-            range,
-        }));
+        self.num_errors += 1;
+
+        let (_, range) = self.current_token_for_error();
+        self.expected_kinds.clear();
+
+        if found != TokenKind::Error && self.last_error_range != Some(range) {
+            self.events.push(Event::Error(ParseError {
+                kind: ParseErrorKind::ExpectedEnd { found },
+                range,
+            }));
+        }
+
+        self.last_error_range = Some(range);
+
+        let m = self.start();
+
+        while !self.at_end() {
+            self.bump();
+        }
+
+        m.complete(self, SyntaxKind::Error);
+    }
+
+    /// Returns how many errors have been reported so far, including those that were suppressed
+    /// because the mistake had already been reported.
+    #[inline]
+    pub(crate) fn num_errors(&self) -> usize {
+        self.num_errors
+    }
+
+    /// Enters a construct that nests, e.g. an expression, unless the construct would be nested
+    /// deeper than the maximum depth. In that case the construct is reported, skipped over and
+    /// `false` is returned, so the caller mustn’t parse it; otherwise, the caller has to call
+    /// `exit` once it’s done parsing the construct.
+    pub(crate) fn enter(&mut self) -> bool {
+        if self.depth < self.max_depth {
+            self.depth += 1;
+            return true;
+        }
+
+        self.num_errors += 1;
+
+        let (_, range) = self.current_token_for_error();
+
+        if self.last_error_range != Some(range) {
+            self.events.push(Event::Error(ParseError {
+                kind: ParseErrorKind::NestedTooDeeply,
+                range,
+            }));
+        }
+
+        self.expected_kinds.clear();
+        self.skip_nested();
+
+        // Whatever comes after the skipped tokens is likely to be unexpected too, since it’s
+        // missing what was skipped, but that’s part of the same mistake.
+        let (_, range) = self.current_token_for_error();
+        self.last_error_range = Some(range);
+
+        false
+    }
+
+    #[inline]
+    pub(crate) fn exit(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Skips over tokens up to the end of the innermost enclosing brackets, without recursing so
+    /// that this works however deeply the skipped tokens are nested.
+    fn skip_nested(&mut self) {
+        let mut m = None;
+        let mut depth = 0_usize;
+
+        loop {
+            match self.peek() {
+                None => break,
+                Some(TokenKind::LParen | TokenKind::LBrace | TokenKind::LBracket) => depth += 1,
+                Some(TokenKind::RParen | TokenKind::RBrace | TokenKind::RBracket) => {
+                    if depth == 0 {
+                        break;
+                    }
+
+                    depth -= 1;
+                }
+                // A `fn` followed by `(` starts a closure, which is part of what’s being skipped,
+                // rather than a definition that parsing could pick up again at.
+                Some(TokenKind::FnKw) if self.next_at(TokenKind::LParen) => {}
+                Some(kind) if depth == 0 && RECOVERY_SET.contains(&kind) => break,
+                Some(_) => {}
+            }
+
+            if m.is_none() {
+                m = Some(self.start());
+            }
 
-        if !self.at_set(RECOVERY_SET) && !self.at_end() {
-            let m = self.start();
             self.bump();
+        }
+
+        if let Some(m) = m {
             m.complete(self, SyntaxKind::Error);
         }
     }
 
+    fn current_token_for_error(&mut self) -> (Option<TokenKind>, TextRange) {
+        match self.source.peek_token() {
+            Some(Token { kind, range, .. }) => (Some(*kind), *range),
+            // If we’re at the end of the input we use the range of the very last token in the
+            // input, or an empty range if there are no tokens at all.
+            None => (None, self.source.last_token_range().unwrap_or_default()),
+        }
+    }
+
+    /// Makes error recovery stop at any of the tokens in `set`, on top of those it already stops
+    /// at, until the matching call to `pop_recovery_set`.
+    #[inline]
+    pub(crate) fn push_recovery_set(&mut self, set: &[TokenKind]) {
+        self.recovery_set_starts.push(self.recovery_sets.len());
+        self.recovery_sets.extend_from_slice(set);
+    }
+
+    #[inline]
+    pub(crate) fn pop_recovery_set(&mut self) {
+        let start = self.recovery_set_starts.pop().unwrap();
+        self.recovery_sets.truncate(start);
+    }
+
+    /// Returns whether error recovery would stop at the current token.
+    pub(crate) fn at_recovery_set(&mut self) -> bool {
+        let Some(kind) = self.peek() else {
+            return false;
+        };
+
+        RECOVERY_SET.contains(&kind) || self.recovery_sets.contains(&kind)
+    }
+
+    /// Returns whether there’s a line break before the current token that ends whatever came
+    /// before it. Line breaks are only significant outside of parentheses.
+    #[inline]
+    pub(crate) fn at_line_break(&mut self) -> bool {
+        self.newlines_significant && self.source.at_line_break()
+    }
+
+    /// Sets whether line breaks are significant until this is called again, returning whether they
+    /// were significant before so that the caller can restore that afterwards.
+    #[inline]
+    pub(crate) fn set_newlines_significant(&mut self, significant: bool) -> bool {
+        mem::replace(&mut self.newlines_significant, significant)
+    }
+
+    /// Returns whether a name followed by `{` is a record expression, e.g. `Point { x: 1 }`. They
+    /// aren’t allowed directly in conditions, where the `{` starts the body instead, e.g. in
+    /// `if a { b }`.
+    #[inline]
+    pub(crate) fn records_allowed(&self) -> bool {
+        self.records_allowed
+    }
+
+    /// Sets whether record expressions are allowed until this is called again, returning whether
+    /// they were allowed before so that the caller can restore that afterwards.
+    #[inline]
+    pub(crate) fn set_records_allowed(&mut self, allowed: bool) -> bool {
+        mem::replace(&mut self.records_allowed, allowed)
+    }
+
     #[inline]
     pub(crate) fn bump(&mut self) {
         self.expected_kinds.clear();
@@ -86,12 +314,23 @@ impl<'t, 'input> Parser<'t, 'input> {
 
     #[inline]
     pub(crate) fn at(&mut self, kind: TokenKind) -> bool {
-        self.expected_kinds.push(kind);
+        if !self.expected_kinds.contains(&kind) {
+            self.expected_kinds.push(kind);
+        }
+
         self.peek() == Some(kind)
     }
 
+    /// Returns whether the token after the current one is of the given kind. Unlike `at`, this
+    /// doesn’t count towards what’s expected, since it only tells apart things that start with the
+    /// same token.
+    #[inline]
+    pub(crate) fn next_at(&mut self, kind: TokenKind) -> bool {
+        self.source.peek_next_kind() == Some(kind)
+    }
+
     #[inline]
-    fn at_set(&mut self, set: &[TokenKind]) -> bool {
+    pub(crate) fn at_set(&mut self, set: &[TokenKind]) -> bool {
         self.peek().is_some_and(|k| set.contains(&k))
     }
 
@@ -135,4 +374,14 @@ Root@0..8
   Comment@0..8 "# hello!""##]],
         );
     }
+
+    #[test]
+    fn parse_block_comment() {
+        check(
+            "#[ hello #[ nested ]# ]#",
+            expect![[r##"
+Root@0..24
+  Comment@0..24 "#[ hello #[ nested ]# ]#""##]],
+        );
+    }
 }
@@ -18,6 +18,12 @@ impl Marker {
         }
     }
 
+    /// Gives up on starting a node here, leaving whatever was parsed after the marker as it is.
+    #[inline]
+    pub(crate) fn abandon(mut self) {
+        self.bomb.defuse();
+    }
+
     pub(crate) fn complete(mut self, p: &mut Parser, kind: SyntaxKind) -> CompletedMarker {
         self.bomb.defuse();
 
@@ -31,16 +37,25 @@ impl Marker {
 
         p.events.push(Event::FinishNode);
 
-        CompletedMarker { pos: self.pos }
+        CompletedMarker {
+            pos: self.pos,
+            kind,
+        }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) struct CompletedMarker {
     pos: usize,
+    kind: SyntaxKind,
 }
 
 impl CompletedMarker {
+    #[inline]
+    pub(crate) fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
     pub(crate) fn precede(self, p: &mut Parser) -> Marker {
         let new_m = p.start();
 
@@ -1,28 +1,108 @@
-use lexer::TokenKind;
+use lexer::{LexError, LexErrorKind, TokenKind};
 use std::fmt;
 use text_size::TextRange;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) struct ParseError {
-    pub(super) expected: Vec<TokenKind>,
-    pub(super) found: Option<TokenKind>,
+pub struct ParseError {
+    pub(super) kind: ParseErrorKind,
     pub(super) range: TextRange,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Unexpected {
+        expected: Vec<TokenKind>,
+        /// is `None` if the input ended before the expected token
+        found: Option<TokenKind>,
+    },
+    NestedTooDeeply,
+    /// A statement or match arm was followed by another token on the same line, without a `;` or
+    /// `,` respectively in between.
+    MissingTerminator {
+        terminator: TokenKind,
+        found: TokenKind,
+    },
+    /// A fragment, e.g. a lone expression, was followed by more input.
+    ExpectedEnd {
+        found: TokenKind,
+    },
+    Lex(LexErrorKind),
+}
+
+impl ParseError {
+    #[inline]
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+}
+
+impl ParseErrorKind {
+    /// Returns a code identifying this kind of error. Codes never change meaning and are never
+    /// reused, so they’re safe to filter diagnostics by.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Unexpected { .. } => "P0001",
+            Self::NestedTooDeeply => "P0002",
+            Self::MissingTerminator { .. } => "P0003",
+            Self::ExpectedEnd { .. } => "P0004",
+            Self::Lex(kind) => kind.code(),
+        }
+    }
+}
+
+impl From<LexError> for ParseError {
+    #[inline]
+    fn from(error: LexError) -> Self {
+        Self {
+            kind: ParseErrorKind::Lex(error.kind),
+            range: error.range,
+        }
+    }
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "error at {}..{}: expected ",
+            "error at {}..{}: {}",
             u32::from(self.range.start()),
             u32::from(self.range.end()),
-        )?;
+            self.kind,
+        )
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (expected, found) = match self {
+            Self::Unexpected { expected, found } => (expected, found),
+            Self::NestedTooDeeply => return write!(f, "expression nested too deeply"),
+            Self::MissingTerminator { terminator, found } => {
+                return write!(f, "expected newline or {terminator}, but found {found}");
+            }
+            Self::ExpectedEnd { found } => {
+                return write!(f, "expected end of input, but found {found}");
+            }
+            Self::Lex(kind) => return write!(f, "{kind}"),
+        };
 
-        let num_expected = self.expected.len();
+        write!(f, "expected ")?;
+
+        let num_expected = expected.len();
         let is_first = |idx| idx == 0;
         let is_last = |idx| idx == num_expected - 1;
 
-        for (idx, expected_kind) in self.expected.iter().enumerate() {
+        for (idx, expected_kind) in expected.iter().enumerate() {
             if is_first(idx) {
                 write!(f, "{expected_kind}")?;
             } else if is_last(idx) {
@@ -32,7 +112,7 @@ impl fmt::Display for ParseError {
             }
         }
 
-        if let Some(found) = self.found {
+        if let Some(found) = found {
             write!(f, ", but found {found}")?;
         }
 
@@ -40,6 +120,8 @@ impl fmt::Display for ParseError {
     }
 }
 
+impl std::error::Error for ParseError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,8 +134,7 @@ mod tests {
         output: &str,
     ) {
         let error = ParseError {
-            expected,
-            found,
+            kind: ParseErrorKind::Unexpected { expected, found },
             range: {
                 let start = range.start.into();
                 let end = range.end.into();
@@ -108,4 +189,45 @@ mod tests {
             "error at 100..105: expected number, identifier, ‘-’ or ‘(’, but found ‘let’",
         );
     }
+
+    #[test]
+    fn lex_error() {
+        let error = ParseError::from(LexError {
+            kind: LexErrorKind::InvalidCharacter('$'),
+            range: TextRange::new(3.into(), 4.into()),
+        });
+
+        assert_eq!(format!("{error}"), "error at 3..4: invalid character ‘$’");
+        assert_eq!(error.code(), "L0001");
+    }
+
+    #[test]
+    fn unexpected_code() {
+        let error = ParseError {
+            kind: ParseErrorKind::Unexpected {
+                expected: vec![TokenKind::RParen],
+                found: None,
+            },
+            range: TextRange::new(0.into(), 1.into()),
+        };
+
+        assert_eq!(error.code(), "P0001");
+    }
+
+    #[test]
+    fn missing_terminator() {
+        let error = ParseError {
+            kind: ParseErrorKind::MissingTerminator {
+                terminator: TokenKind::Semicolon,
+                found: TokenKind::Number,
+            },
+            range: TextRange::new(2.into(), 3.into()),
+        };
+
+        assert_eq!(
+            format!("{error}"),
+            "error at 2..3: expected newline or ‘;’, but found number",
+        );
+        assert_eq!(error.code(), "P0003");
+    }
 }
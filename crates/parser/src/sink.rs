@@ -2,9 +2,10 @@ use crate::Parse;
 use crate::event::Event;
 use crate::parser::ParseError;
 use lexer::Token;
+use lexer::{LexError, TokenKind};
 use rowan::{GreenNodeBuilder, Language};
 use std::mem;
-use syntax::ChouLanguage;
+use syntax::{ChouLanguage, SyntaxKind};
 
 #[derive(Debug)]
 pub(crate) struct Sink<'t, 'input> {
@@ -13,17 +14,23 @@ pub(crate) struct Sink<'t, 'input> {
     cursor: usize,
     events: Vec<Event>,
     errors: Vec<ParseError>,
+    lex_errors: Vec<LexError>,
 }
 
 impl<'t, 'input> Sink<'t, 'input> {
     #[inline]
-    pub(crate) fn new(tokens: &'t [Token<'input>], events: Vec<Event>) -> Self {
+    pub(crate) fn new(
+        tokens: &'t [Token<'input>],
+        events: Vec<Event>,
+        lex_errors: Vec<LexError>,
+    ) -> Self {
         Self {
             builder: GreenNodeBuilder::new(),
             tokens,
             cursor: 0,
             events,
             errors: Vec::new(),
+            lex_errors,
         }
     }
 
@@ -58,22 +65,97 @@ impl<'t, 'input> Sink<'t, 'input> {
                         };
                     }
 
-                    for kind in kinds.into_iter().rev() {
+                    let mut kinds = kinds.into_iter().rev();
+
+                    let outermost = kinds.next().unwrap();
+                    self.builder
+                        .start_node(ChouLanguage::kind_to_raw(outermost));
+
+                    // The only trivia that can be left before a node preceded by others is at the
+                    // start of the input, and it goes in the outermost of those nodes rather than
+                    // the innermost one, e.g. in ` a + b` it goes in the `a + b` rather than the
+                    // `a`. That way only the root can have trivia before its first token.
+                    if kinds.len() > 0 {
+                        self.eat_trivia();
+                    }
+
+                    for kind in kinds {
                         self.builder.start_node(ChouLanguage::kind_to_raw(kind));
                     }
                 }
                 Event::AddToken => self.token(),
                 Event::FinishNode => self.builder.finish_node(),
                 Event::Error(error) => self.errors.push(error),
-                Event::Placeholder => {}
+                // Placeholders are left by abandoned markers and forward parents that have already
+                // been started, neither of which adds anything to the tree. An abandoned marker
+                // can come before the root node is started, so we mustn’t eat any trivia there.
+                Event::Placeholder => continue,
             }
 
-            self.eat_trivia();
+            // Doc comments belong to the definition that follows them, so we leave them to be
+            // eaten once that definition’s node has been started.
+            if self.next_event_starts_definition(idx) {
+                self.eat_trivia_before_doc_comments();
+            } else {
+                self.eat_trivia();
+            }
         }
 
+        // The sort is stable, so lex errors end up after any parse errors starting at the same
+        // position.
+        let mut errors = self.errors;
+        errors.extend(self.lex_errors.into_iter().map(ParseError::from));
+        errors.sort_by_key(|error| error.range().start());
+
         Parse {
             green_node: self.builder.finish(),
-            errors: self.errors,
+            errors,
+        }
+    }
+
+    fn next_event_starts_definition(&self, idx: usize) -> bool {
+        // Finishing nodes doesn’t consume any tokens, so we look past those to find out what
+        // the doc comments would end up in.
+        let next_event = self.events[idx + 1..].iter().find(|event| {
+            !matches!(
+                event,
+                Event::Placeholder | Event::FinishNode | Event::Error(_)
+            )
+        });
+
+        matches!(
+            next_event,
+            Some(Event::StartNode {
+                kind: SyntaxKind::VariableDef
+                    | SyntaxKind::FnDef
+                    | SyntaxKind::StructDef
+                    | SyntaxKind::EnumDef
+                    | SyntaxKind::ModDef,
+                ..
+            })
+        )
+    }
+
+    /// Eats trivia up to (but not including) the run of doc comments directly preceding the next
+    /// token, if there is one. Only whitespace may separate the doc comments in that run.
+    fn eat_trivia_before_doc_comments(&mut self) {
+        let mut doc_comments_start = None;
+
+        for (idx, token) in self.tokens.iter().enumerate().skip(self.cursor) {
+            match token.kind {
+                TokenKind::DocComment => {
+                    doc_comments_start.get_or_insert(idx);
+                }
+                TokenKind::Whitespace => {}
+                TokenKind::Comment => doc_comments_start = None,
+                _ => break,
+            }
+        }
+
+        let end = doc_comments_start.unwrap_or(self.tokens.len());
+
+        while self.cursor < end && self.tokens[self.cursor].kind.is_trivia() {
+            self.token();
         }
     }
 
@@ -30,11 +30,34 @@ impl<'t, 'input> Source<'t, 'input> {
     }
 
     #[inline]
-    pub(crate) fn peek_token(&mut self) -> Option<&Token> {
+    pub(crate) fn peek_token(&mut self) -> Option<&Token<'_>> {
         self.eat_trivia();
         self.peek_token_raw()
     }
 
+    /// Returns the kind of the token after the next one, skipping over trivia.
+    pub(crate) fn peek_next_kind(&mut self) -> Option<TokenKind> {
+        self.eat_trivia();
+
+        self.tokens[self.cursor..]
+            .iter()
+            .skip(1)
+            .map(|Token { kind, .. }| *kind)
+            .find(|kind| !kind.is_trivia())
+    }
+
+    /// Returns whether there’s a line break between the previous token and the next one, whether
+    /// in whitespace or inside a block comment.
+    pub(crate) fn at_line_break(&mut self) -> bool {
+        self.eat_trivia();
+
+        self.tokens[..self.cursor]
+            .iter()
+            .rev()
+            .take_while(|token| token.kind.is_trivia())
+            .any(|token| token.text.contains('\n'))
+    }
+
     #[inline]
     fn eat_trivia(&mut self) {
         while self.at_trivia() {
@@ -58,7 +81,7 @@ impl<'t, 'input> Source<'t, 'input> {
     }
 
     #[inline]
-    fn peek_token_raw(&self) -> Option<&Token> {
+    fn peek_token_raw(&self) -> Option<&Token<'_>> {
         self.tokens.get(self.cursor)
     }
 }
@@ -10,26 +10,105 @@ pub enum SyntaxKind {
     Whitespace,
     FnKw,
     LetKw,
+    StructKw,
+    EnumKw,
+    ModKw,
+    UseKw,
+    IfKw,
+    ElseKw,
+    MatchKw,
+    WhileKw,
+    LoopKw,
+    BreakKw,
+    ContinueKw,
+    TrueKw,
+    FalseKw,
     Ident,
     Number,
+    Float,
+    String,
     Plus,
     Minus,
     Star,
     Slash,
     Equals,
+    PlusEquals,
+    MinusEquals,
+    StarEquals,
+    SlashEquals,
+    EqualsEquals,
+    BangEquals,
+    Less,
+    LessEquals,
+    Greater,
+    GreaterEquals,
+    AndAnd,
+    OrOr,
+    Pipe,
+    Bang,
+    Comma,
+    Dot,
+    Colon,
+    ColonColon,
+    Arrow,
+    FatArrow,
+    Semicolon,
     LParen,
     RParen,
     LBrace,
     RBrace,
+    LBracket,
+    RBracket,
     Comment,
+    DocComment,
     Error,
     Root,
+    ArgList,
+    ArrayExpr,
+    AssignExpr,
+    BlockExpr,
+    BreakExpr,
+    CallExpr,
+    ClosureExpr,
+    ContinueExpr,
+    EnumDef,
+    FieldDef,
+    FieldDefList,
+    FieldExpr,
+    FnDef,
+    IdentPat,
+    IfExpr,
+    IndexExpr,
     InfixExpr,
     Literal,
+    LiteralPat,
+    LoopExpr,
+    MatchArm,
+    MatchArmList,
+    MatchExpr,
+    MatchGuard,
+    ModDef,
+    Param,
+    ParamList,
     ParenExpr,
+    ParenPat,
+    Path,
+    PathExpr,
     PrefixExpr,
+    RecordExpr,
+    RecordField,
+    RecordFieldList,
+    StructDef,
+    TupleExpr,
+    TuplePat,
+    TypeRef,
+    UseDef,
     VariableDef,
     VariableRef,
+    Variant,
+    VariantList,
+    VariantPat,
+    WhileExpr,
 }
 
 impl From<TokenKind> for SyntaxKind {
@@ -39,23 +118,69 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::Whitespace => Self::Whitespace,
             TokenKind::FnKw => Self::FnKw,
             TokenKind::LetKw => Self::LetKw,
+            TokenKind::StructKw => Self::StructKw,
+            TokenKind::EnumKw => Self::EnumKw,
+            TokenKind::ModKw => Self::ModKw,
+            TokenKind::UseKw => Self::UseKw,
+            TokenKind::IfKw => Self::IfKw,
+            TokenKind::ElseKw => Self::ElseKw,
+            TokenKind::MatchKw => Self::MatchKw,
+            TokenKind::WhileKw => Self::WhileKw,
+            TokenKind::LoopKw => Self::LoopKw,
+            TokenKind::BreakKw => Self::BreakKw,
+            TokenKind::ContinueKw => Self::ContinueKw,
+            TokenKind::TrueKw => Self::TrueKw,
+            TokenKind::FalseKw => Self::FalseKw,
             TokenKind::Ident => Self::Ident,
             TokenKind::Number => Self::Number,
+            TokenKind::Float => Self::Float,
+            TokenKind::String => Self::String,
             TokenKind::Plus => Self::Plus,
             TokenKind::Minus => Self::Minus,
             TokenKind::Star => Self::Star,
             TokenKind::Slash => Self::Slash,
             TokenKind::Equals => Self::Equals,
+            TokenKind::PlusEquals => Self::PlusEquals,
+            TokenKind::MinusEquals => Self::MinusEquals,
+            TokenKind::StarEquals => Self::StarEquals,
+            TokenKind::SlashEquals => Self::SlashEquals,
+            TokenKind::EqualsEquals => Self::EqualsEquals,
+            TokenKind::BangEquals => Self::BangEquals,
+            TokenKind::Less => Self::Less,
+            TokenKind::LessEquals => Self::LessEquals,
+            TokenKind::Greater => Self::Greater,
+            TokenKind::GreaterEquals => Self::GreaterEquals,
+            TokenKind::AndAnd => Self::AndAnd,
+            TokenKind::OrOr => Self::OrOr,
+            TokenKind::Pipe => Self::Pipe,
+            TokenKind::Bang => Self::Bang,
+            TokenKind::Comma => Self::Comma,
+            TokenKind::Dot => Self::Dot,
+            TokenKind::Colon => Self::Colon,
+            TokenKind::ColonColon => Self::ColonColon,
+            TokenKind::Arrow => Self::Arrow,
+            TokenKind::FatArrow => Self::FatArrow,
+            TokenKind::Semicolon => Self::Semicolon,
             TokenKind::LParen => Self::LParen,
             TokenKind::RParen => Self::RParen,
             TokenKind::LBrace => Self::LBrace,
             TokenKind::RBrace => Self::RBrace,
+            TokenKind::LBracket => Self::LBracket,
+            TokenKind::RBracket => Self::RBracket,
             TokenKind::Comment => Self::Comment,
+            TokenKind::DocComment => Self::DocComment,
             TokenKind::Error => Self::Error,
         }
     }
 }
 
+impl SyntaxKind {
+    #[inline]
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Self::Whitespace | Self::Comment | Self::DocComment)
+    }
+}
+
 pub type SyntaxNode = rowan::SyntaxNode<ChouLanguage>;
 pub type SyntaxElement = rowan::SyntaxElement<ChouLanguage>;
 pub type SyntaxToken = rowan::SyntaxToken<ChouLanguage>;